@@ -0,0 +1,27 @@
+//! A module for interacting with the WorkOS Audit Logs API.
+//!
+//! [WorkOS Docs: Audit Logs Guide](https://workos.com/docs/audit-logs)
+
+mod operations;
+mod queue;
+mod types;
+
+pub use operations::*;
+pub use queue::*;
+pub use types::*;
+
+use crate::WorkOs;
+
+/// Audit Logs.
+///
+/// [WorkOS Docs: Audit Logs Guide](https://workos.com/docs/audit-logs)
+pub struct AuditLogs<'a> {
+    workos: &'a WorkOs,
+}
+
+impl<'a> AuditLogs<'a> {
+    /// Returns a new [`AuditLogs`] instance for the provided WorkOS client.
+    pub fn new(workos: &'a WorkOs) -> Self {
+        Self { workos }
+    }
+}
@@ -0,0 +1,3 @@
+mod create_audit_log_event;
+
+pub use create_audit_log_event::*;
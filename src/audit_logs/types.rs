@@ -0,0 +1,3 @@
+mod audit_log_event;
+
+pub use audit_log_event::*;
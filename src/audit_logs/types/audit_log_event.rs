@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Metadata, Timestamp};
+
+/// The actor who performed the action of an [`AuditLogEvent`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogActor {
+    /// The unique identifier of the actor.
+    pub id: String,
+
+    /// The type of the actor.
+    pub r#type: String,
+
+    /// The name of the actor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Object containing metadata key/value pairs associated with the actor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// A target of the action of an [`AuditLogEvent`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogTarget {
+    /// The unique identifier of the target.
+    pub id: String,
+
+    /// The type of the target.
+    pub r#type: String,
+
+    /// The name of the target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Object containing metadata key/value pairs associated with the target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The context in which an [`AuditLogEvent`] occurred.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogContext {
+    /// The IP address or location of the actor.
+    pub location: String,
+
+    /// The user agent of the actor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+/// [WorkOS Docs: Audit Log Event](https://workos.com/docs/reference/audit-logs/event)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogEvent {
+    /// The action that was performed, e.g. `user.signed_in`.
+    pub action: String,
+
+    /// The timestamp indicating when the action occurred.
+    pub occurred_at: Timestamp,
+
+    /// The version of the event schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+
+    /// The actor who performed the action.
+    pub actor: AuditLogActor,
+
+    /// The targets of the action.
+    pub targets: Vec<AuditLogTarget>,
+
+    /// The context in which the action occurred.
+    pub context: AuditLogContext,
+
+    /// Object containing metadata key/value pairs associated with the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
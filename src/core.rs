@@ -1,7 +1,13 @@
 mod error;
+mod etag_cache;
+mod rate_limiter;
 mod response;
+mod retry;
 mod types;
 
 pub use error::*;
+pub(crate) use etag_cache::*;
+pub(crate) use rate_limiter::*;
 pub(crate) use response::*;
+pub(crate) use retry::*;
 pub use types::*;
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use thiserror::Error;
 
 /// A JSON or text body.
@@ -22,13 +24,19 @@ pub enum WorkOsError<E> {
     Unauthorized,
 
     /// An unknown error response was received from the WorkOS API.
+    ///
+    /// More fields may be added in the future, so match it with `..`.
     #[error("unknown error")]
+    #[non_exhaustive]
     Unknown {
         /// The response status code.
         status: reqwest::StatusCode,
 
         /// The response body.
         body: JsonOrText,
+
+        /// The delay requested by the `Retry-After` response header, if any.
+        retry_after: Option<Duration>,
     },
 
     /// An error occurred while parsing a URL.
@@ -44,5 +52,22 @@ pub enum WorkOsError<E> {
     RequestError(#[from] reqwest::Error),
 }
 
+impl<E> WorkOsError<E> {
+    /// The delay before a rate limited request may be retried.
+    ///
+    /// Returns `None` if the WorkOS API did not respond with `429 Too Many Requests`. Otherwise
+    /// returns the delay requested by the `Retry-After` header, or zero if the header is missing.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            WorkOsError::Unknown {
+                status: reqwest::StatusCode::TOO_MANY_REQUESTS,
+                retry_after,
+                ..
+            } => Some(retry_after.unwrap_or_default()),
+            _ => None,
+        }
+    }
+}
+
 /// A WorkOS SDK result.
 pub type WorkOsResult<T, E> = Result<T, WorkOsError<E>>;
@@ -0,0 +1,192 @@
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+use reqwest::header::{ETAG, HeaderValue, IF_NONE_MATCH};
+use reqwest::{RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::{ResponseExt, WorkOsResult};
+
+struct Entry {
+    etag: HeaderValue,
+    value: Arc<dyn Any + Send + Sync>,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct ETagCacheState {
+    entries: HashMap<Url, Entry>,
+    /// The URLs of the entries by when they were last used, least recently used first.
+    recency: BTreeMap<u64, Url>,
+    clock: u64,
+}
+
+impl ETagCacheState {
+    fn get(&mut self, url: &Url) -> Option<&Entry> {
+        let entry = self.entries.get_mut(url)?;
+
+        self.clock += 1;
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(self.clock, url.clone());
+        entry.last_used = self.clock;
+
+        Some(entry)
+    }
+
+    fn insert(
+        &mut self,
+        url: &Url,
+        etag: HeaderValue,
+        value: Arc<dyn Any + Send + Sync>,
+        max_entries: usize,
+    ) {
+        self.remove(url);
+
+        while self.entries.len() >= max_entries {
+            let Some((_, url)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&url);
+        }
+
+        self.clock += 1;
+        self.recency.insert(self.clock, url.clone());
+        self.entries.insert(
+            url.clone(),
+            Entry {
+                etag,
+                value,
+                last_used: self.clock,
+            },
+        );
+    }
+
+    fn remove(&mut self, url: &Url) {
+        if let Some(entry) = self.entries.remove(url) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+}
+
+/// A cache of responses keyed by URL, revalidated using the `ETag` response header.
+///
+/// Holds at most `max_entries` responses, evicting the least recently used one when full.
+pub(crate) struct ETagCache {
+    max_entries: NonZeroUsize,
+    state: Mutex<ETagCacheState>,
+}
+
+impl ETagCache {
+    pub(crate) fn new(max_entries: NonZeroUsize) -> Self {
+        Self {
+            max_entries,
+            state: Mutex::new(ETagCacheState::default()),
+        }
+    }
+
+    /// Sends the request with `If-None-Match` when a response for the URL is cached,
+    /// and returns the cached response when the API responds with `304 Not Modified`.
+    pub(crate) async fn get_json<T, E>(
+        &self,
+        url: &Url,
+        request: RequestBuilder,
+    ) -> WorkOsResult<T, E>
+    where
+        T: DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        let cached = self
+            .state
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(url)
+            .and_then(|entry| Some((entry.etag.clone(), entry.value.downcast_ref::<T>()?.clone())));
+
+        let request = match &cached {
+            Some((etag, _)) => request.header(IF_NONE_MATCH, etag),
+            None => request,
+        };
+
+        let response = request.send().await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some((_, value)) = cached
+        {
+            return Ok(value);
+        }
+
+        let response = response.handle_unauthorized_or_generic_error().await?;
+        let etag = response.headers().get(ETAG).cloned();
+        let value = response.json::<T>().await?;
+
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        match etag {
+            Some(etag) => {
+                state.insert(url, etag, Arc::new(value.clone()), self.max_entries.get());
+            }
+            None => {
+                state.remove(url);
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_evicts_the_least_recently_used_response_when_full() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mut fetched = Vec::new();
+        let mut revalidated = Vec::new();
+        for (path, fetches, revalidations) in [("/a", 1, 2), ("/b", 2, 0), ("/c", 1, 0)] {
+            fetched.push(
+                server
+                    .mock("GET", path)
+                    .match_header("If-None-Match", Matcher::Missing)
+                    .with_status(200)
+                    .with_header("ETag", "\"33a64df5\"")
+                    .with_body("\"fresh\"")
+                    .expect(fetches)
+                    .create_async()
+                    .await,
+            );
+            revalidated.push(
+                server
+                    .mock("GET", path)
+                    .match_header("If-None-Match", "\"33a64df5\"")
+                    .with_status(304)
+                    .expect(revalidations)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let cache = ETagCache::new(NonZeroUsize::new(2).unwrap());
+        let client = reqwest::Client::new();
+        let base_url = Url::parse(&server.url()).unwrap();
+
+        // Using `/a` again makes `/b` the least recently used response, so `/c` evicts it.
+        for path in ["/a", "/b", "/a", "/c", "/a", "/b"] {
+            let url = base_url.join(path).unwrap();
+            let value = cache
+                .get_json::<String, ()>(&url, client.get(url.clone()))
+                .await
+                .unwrap();
+
+            assert_eq!(value, "fresh");
+        }
+
+        for mock in fetched.iter().chain(&revalidated) {
+            mock.assert_async().await;
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::RateLimit;
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// A token bucket that enforces a [`RateLimit`].
+pub(crate) struct RateLimiter {
+    rate_limit: RateLimit,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate_limit: RateLimit) -> Self {
+        Self {
+            rate_limit,
+            bucket: Mutex::new(Bucket {
+                tokens: rate_limit.burst.get() as f64,
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request may be sent.
+    ///
+    /// Callers are served in the order they call this, as each call reserves the next available slot.
+    pub(crate) async fn acquire(&self) {
+        let rate = self.rate_limit.requests_per_second.get() as f64;
+        let capacity = self.rate_limit.burst.get() as f64;
+
+        let wait = {
+            let mut bucket = self.bucket.lock().unwrap_or_else(|err| err.into_inner());
+
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+
+            bucket.tokens = (bucket.tokens + elapsed * rate).min(capacity) - 1.0;
+            bucket.updated_at = now;
+
+            (bucket.tokens < 0.0).then(|| Duration::from_secs_f64(-bucket.tokens / rate))
+        };
+
+        if let Some(wait) = wait {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::NonZeroU32;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_allows_a_burst_and_then_spaces_out_requests() {
+        let rate_limiter = RateLimiter::new(RateLimit {
+            requests_per_second: NonZeroU32::new(20).unwrap(),
+            burst: NonZeroU32::new(2).unwrap(),
+        });
+
+        let start = Instant::now();
+        rate_limiter.acquire().await;
+        rate_limiter.acquire().await;
+
+        assert!(start.elapsed() < Duration::from_millis(40));
+
+        rate_limiter.acquire().await;
+        rate_limiter.acquire().await;
+
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+}
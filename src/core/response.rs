@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::RETRY_AFTER;
 use reqwest::{Response, StatusCode};
 
 use crate::{JsonOrText, WorkOsError, WorkOsResult};
@@ -30,6 +34,8 @@ impl ResponseExt for Response {
     async fn handle_generic_error<E>(self) -> WorkOsResult<Self, E> {
         let status = self.status();
         if status.is_client_error() || status.is_server_error() {
+            let retry_after = parse_retry_after(&self);
+
             if self
                 .headers()
                 .get("content-type")
@@ -40,6 +46,7 @@ impl ResponseExt for Response {
                     Ok(value) => Err(WorkOsError::Unknown {
                         status,
                         body: JsonOrText::Json(value),
+                        retry_after,
                     }),
                     Err(err) => Err(WorkOsError::RequestError(err)),
                 }
@@ -48,6 +55,7 @@ impl ResponseExt for Response {
                     Ok(text) => Err(WorkOsError::Unknown {
                         status,
                         body: JsonOrText::Text(text),
+                        retry_after,
                     }),
                     Err(err) => Err(WorkOsError::RequestError(err)),
                 }
@@ -63,3 +71,20 @@ impl ResponseExt for Response {
             .await
     }
 }
+
+/// Parses the `Retry-After` header, which is either a number of seconds or an HTTP date.
+fn parse_retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
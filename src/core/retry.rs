@@ -0,0 +1,110 @@
+use std::future::Future;
+use std::time::Duration;
+
+/// How an operation is retried by [`retry`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RetryPolicy {
+    /// How many times a failed operation is retried before giving up.
+    pub(crate) max_retries: u32,
+
+    /// The minimum delay before the first retry. The delay is doubled for each subsequent retry.
+    pub(crate) retry_delay: Duration,
+}
+
+/// Runs the operation, retrying errors for which `retry_after` returns a delay.
+///
+/// Waits for the longer of that delay (e.g. from a `Retry-After` header) and the exponential
+/// backoff delay before each retry.
+pub(crate) async fn retry<T, E, F, Fut, R>(
+    policy: RetryPolicy,
+    operation: F,
+    retry_after: R,
+) -> Result<T, E>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    R: Fn(&E) -> Option<Duration>,
+{
+    let mut delay = policy.retry_delay;
+    let mut attempt = 0;
+    loop {
+        match operation().await {
+            Err(err) if attempt < policy.max_retries => match retry_after(&err) {
+                Some(retry_after) => {
+                    tokio::time::sleep(retry_after.max(delay)).await;
+
+                    delay *= 2;
+                    attempt += 1;
+                }
+                None => return Err(err),
+            },
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Instant;
+
+    use super::*;
+
+    const POLICY: RetryPolicy = RetryPolicy {
+        max_retries: 2,
+        retry_delay: Duration::from_millis(1),
+    };
+
+    #[tokio::test]
+    async fn it_waits_for_the_longer_of_retry_after_and_the_backoff() {
+        let attempts = AtomicU32::new(0);
+        let started_at = Instant::now();
+
+        let result = retry(
+            POLICY,
+            || async {
+                match attempts.fetch_add(1, Ordering::Relaxed) {
+                    0 => Err(Duration::from_millis(200)),
+                    _ => Ok(()),
+                }
+            },
+            |retry_after: &Duration| Some(*retry_after),
+        )
+        .await;
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(attempts.load(Ordering::Relaxed), 2);
+        assert!(started_at.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn it_gives_up_after_max_retries_or_when_not_retryable() {
+        let attempts = AtomicU32::new(0);
+        let result = retry(
+            POLICY,
+            || async {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err::<(), _>("unavailable")
+            },
+            |_| Some(Duration::ZERO),
+        )
+        .await;
+
+        assert_eq!(result, Err("unavailable"));
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+
+        let attempts = AtomicU32::new(0);
+        let result = retry(
+            POLICY,
+            || async {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err::<(), _>("invalid")
+            },
+            |_| None,
+        )
+        .await;
+
+        assert_eq!(result, Err("invalid"));
+        assert_eq!(attempts.load(Ordering::Relaxed), 1);
+    }
+}
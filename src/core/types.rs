@@ -2,6 +2,8 @@ mod api_key;
 mod metadata;
 mod paginated_list;
 mod pagination_params;
+mod rate_limit;
+#[cfg(feature = "user-management")]
 mod remote_jwk_set;
 mod timestamps;
 mod unpaginated_list;
@@ -11,6 +13,8 @@ pub use api_key::*;
 pub use metadata::*;
 pub use paginated_list::*;
 pub use pagination_params::*;
+pub use rate_limit::*;
+#[cfg(feature = "user-management")]
 pub use remote_jwk_set::*;
 pub use timestamps::*;
 pub use unpaginated_list::*;
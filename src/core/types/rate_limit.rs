@@ -0,0 +1,14 @@
+use std::num::NonZeroU32;
+
+/// A client-side limit on the rate of requests sent to the WorkOS API.
+///
+/// Requests are allowed in bursts of up to [`burst`](RateLimit::burst) requests, after which
+/// they are spaced out to [`requests_per_second`](RateLimit::requests_per_second).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The sustained number of requests per second.
+    pub requests_per_second: NonZeroU32,
+
+    /// The maximum number of requests that may be sent at once.
+    pub burst: NonZeroU32,
+}
@@ -30,20 +30,33 @@ impl From<FindJwkError> for WorkOsError<FindJwkError> {
 /// Remote JSON Web Key Set (JWKS).
 #[derive(Clone)]
 pub struct RemoteJwkSet {
-    client: reqwest::Client,
-    url: Url,
+    remote: Option<(reqwest::Client, Url)>,
     jwks: Arc<Mutex<Option<Entry>>>,
 }
 
 impl RemoteJwkSet {
     pub(crate) fn new(client: reqwest::Client, url: Url) -> Self {
         RemoteJwkSet {
-            client,
-            url,
+            remote: Some((client, url)),
             jwks: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Returns a key set that always contains the given keys and is never fetched.
+    ///
+    /// This is useful to verify sessions signed by local keys in tests, so it is only available
+    /// with the `testing` feature.
+    #[cfg(feature = "testing")]
+    pub fn from_jwks(jwks: JwkSet) -> Self {
+        RemoteJwkSet {
+            remote: None,
+            jwks: Arc::new(Mutex::new(Some((
+                jwks,
+                DateTime::<Utc>::MAX_UTC.fixed_offset(),
+            )))),
+        }
+    }
+
     /// Find the key in the set that matches the given key id, if any.
     pub async fn find(&self, kid: &str) -> WorkOsResult<Option<Jwk>, FindJwkError> {
         {
@@ -59,9 +72,12 @@ impl RemoteJwkSet {
             }
         }
 
-        let new_jwks = self
-            .client
-            .get(self.url.as_str())
+        let Some((client, url)) = &self.remote else {
+            return Ok(None);
+        };
+
+        let new_jwks = client
+            .get(url.as_str())
             .send()
             .await?
             .handle_unauthorized_or_generic_error()
@@ -1,6 +1,7 @@
 mod delete_directory;
 mod get_directory;
 mod get_directory_group;
+mod get_directory_snapshot;
 mod get_directory_user;
 mod list_directories;
 mod list_directory_groups;
@@ -9,6 +10,7 @@ mod list_directory_users;
 pub use delete_directory::*;
 pub use get_directory::*;
 pub use get_directory_group::*;
+pub use get_directory_snapshot::*;
 pub use get_directory_user::*;
 pub use list_directories::*;
 pub use list_directory_groups::*;
@@ -1,9 +1,11 @@
 mod directory;
 mod directory_group;
+mod directory_snapshot;
 mod directory_type;
 mod directory_user;
 
 pub use directory::*;
 pub use directory_group::*;
+pub use directory_snapshot::*;
 pub use directory_type::*;
 pub use directory_user::*;
@@ -0,0 +1,372 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::directory_sync::{
+    DirectoryGroup, DirectoryGroupId, DirectoryId, DirectoryUser, DirectoryUserId,
+};
+
+/// A point-in-time copy of all users and groups in a directory, with indexed lookups.
+///
+/// Use [`GetDirectorySnapshot`](crate::directory_sync::GetDirectorySnapshot) to load a snapshot
+/// and [`DirectorySnapshot::diff`] to detect changes between two snapshots.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectorySnapshot {
+    directory_id: DirectoryId,
+    users: BTreeMap<DirectoryUserId, DirectoryUser>,
+    groups: BTreeMap<DirectoryGroupId, DirectoryGroup>,
+    users_by_email: BTreeMap<String, DirectoryUserId>,
+    users_by_idp_id: BTreeMap<String, DirectoryUserId>,
+    groups_by_idp_id: BTreeMap<String, DirectoryGroupId>,
+    group_members: BTreeMap<DirectoryGroupId, BTreeSet<DirectoryUserId>>,
+}
+
+impl DirectorySnapshot {
+    /// Returns a new [`DirectorySnapshot`] from the users and groups of a directory.
+    pub fn new(
+        directory_id: DirectoryId,
+        users: Vec<DirectoryUser>,
+        groups: Vec<DirectoryGroup>,
+    ) -> Self {
+        let mut users_by_email = BTreeMap::new();
+        let mut users_by_idp_id = BTreeMap::new();
+        let mut group_members: BTreeMap<DirectoryGroupId, BTreeSet<DirectoryUserId>> =
+            BTreeMap::new();
+
+        for user in &users {
+            for email in user.emails.iter().filter_map(|email| email.value.as_ref()) {
+                users_by_email.insert(email.to_lowercase(), user.id.clone());
+            }
+
+            users_by_idp_id.insert(user.idp_id.clone(), user.id.clone());
+
+            for group in &user.groups {
+                group_members
+                    .entry(group.id.clone())
+                    .or_default()
+                    .insert(user.id.clone());
+            }
+        }
+
+        let groups_by_idp_id = groups
+            .iter()
+            .map(|group| (group.idp_id.clone(), group.id.clone()))
+            .collect();
+
+        Self {
+            directory_id,
+            users: users
+                .into_iter()
+                .map(|user| (user.id.clone(), user))
+                .collect(),
+            groups: groups
+                .into_iter()
+                .map(|group| (group.id.clone(), group))
+                .collect(),
+            users_by_email,
+            users_by_idp_id,
+            groups_by_idp_id,
+            group_members,
+        }
+    }
+
+    /// The ID of the directory the snapshot was taken of.
+    pub fn directory_id(&self) -> &DirectoryId {
+        &self.directory_id
+    }
+
+    /// All users in the directory.
+    pub fn users(&self) -> impl Iterator<Item = &DirectoryUser> {
+        self.users.values()
+    }
+
+    /// All groups in the directory.
+    pub fn groups(&self) -> impl Iterator<Item = &DirectoryGroup> {
+        self.groups.values()
+    }
+
+    /// Finds a user by ID.
+    pub fn user(&self, id: &DirectoryUserId) -> Option<&DirectoryUser> {
+        self.users.get(id)
+    }
+
+    /// Finds a group by ID.
+    pub fn group(&self, id: &DirectoryGroupId) -> Option<&DirectoryGroup> {
+        self.groups.get(id)
+    }
+
+    /// Finds a user by any of their email addresses, ignoring case.
+    pub fn user_by_email(&self, email: &str) -> Option<&DirectoryUser> {
+        self.users_by_email
+            .get(&email.to_lowercase())
+            .and_then(|id| self.users.get(id))
+    }
+
+    /// Finds a user by the identifier assigned by the Directory Provider.
+    pub fn user_by_idp_id(&self, idp_id: &str) -> Option<&DirectoryUser> {
+        self.users_by_idp_id
+            .get(idp_id)
+            .and_then(|id| self.users.get(id))
+    }
+
+    /// Finds a group by the identifier assigned by the Directory Provider.
+    pub fn group_by_idp_id(&self, idp_id: &str) -> Option<&DirectoryGroup> {
+        self.groups_by_idp_id
+            .get(idp_id)
+            .and_then(|id| self.groups.get(id))
+    }
+
+    /// The users that are a member of the given group.
+    pub fn group_members(&self, id: &DirectoryGroupId) -> impl Iterator<Item = &DirectoryUser> {
+        self.group_members
+            .get(id)
+            .into_iter()
+            .flatten()
+            .filter_map(|user_id| self.users.get(user_id))
+    }
+
+    /// The groups the given user is a member of.
+    pub fn user_groups(&self, id: &DirectoryUserId) -> impl Iterator<Item = &DirectoryGroup> {
+        self.users
+            .get(id)
+            .into_iter()
+            .flat_map(|user| &user.groups)
+            .filter_map(|group| self.groups.get(&group.id))
+    }
+
+    /// Compares this snapshot to a newer one and returns the users and groups that were added,
+    /// removed or changed.
+    pub fn diff<'a>(&'a self, other: &'a DirectorySnapshot) -> DirectorySnapshotDiff<'a> {
+        let (added_users, removed_users, changed_users) = diff_maps(&self.users, &other.users);
+        let (added_groups, removed_groups, changed_groups) = diff_maps(&self.groups, &other.groups);
+
+        DirectorySnapshotDiff {
+            added_users,
+            removed_users,
+            changed_users,
+            added_groups,
+            removed_groups,
+            changed_groups,
+        }
+    }
+}
+
+/// A record that is present in both snapshots of a [`DirectorySnapshotDiff`], but differs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DirectorySnapshotChange<'a, T> {
+    /// The record in the older snapshot.
+    pub previous: &'a T,
+
+    /// The record in the newer snapshot.
+    pub current: &'a T,
+}
+
+/// The differences between two [`DirectorySnapshot`]s, as returned by [`DirectorySnapshot::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DirectorySnapshotDiff<'a> {
+    /// Users only present in the newer snapshot.
+    pub added_users: Vec<&'a DirectoryUser>,
+
+    /// Users only present in the older snapshot.
+    pub removed_users: Vec<&'a DirectoryUser>,
+
+    /// Users present in both snapshots that differ.
+    pub changed_users: Vec<DirectorySnapshotChange<'a, DirectoryUser>>,
+
+    /// Groups only present in the newer snapshot.
+    pub added_groups: Vec<&'a DirectoryGroup>,
+
+    /// Groups only present in the older snapshot.
+    pub removed_groups: Vec<&'a DirectoryGroup>,
+
+    /// Groups present in both snapshots that differ.
+    pub changed_groups: Vec<DirectorySnapshotChange<'a, DirectoryGroup>>,
+}
+
+impl DirectorySnapshotDiff<'_> {
+    /// Whether the snapshots are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added_users.is_empty()
+            && self.removed_users.is_empty()
+            && self.changed_users.is_empty()
+            && self.added_groups.is_empty()
+            && self.removed_groups.is_empty()
+            && self.changed_groups.is_empty()
+    }
+}
+
+type MapDiff<'a, T> = (Vec<&'a T>, Vec<&'a T>, Vec<DirectorySnapshotChange<'a, T>>);
+
+fn diff_maps<'a, K: Ord, T: PartialEq>(
+    previous: &'a BTreeMap<K, T>,
+    current: &'a BTreeMap<K, T>,
+) -> MapDiff<'a, T> {
+    let added = current
+        .iter()
+        .filter(|(id, _)| !previous.contains_key(id))
+        .map(|(_, value)| value)
+        .collect();
+
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+
+    for (id, previous) in previous {
+        match current.get(id) {
+            None => removed.push(previous),
+            Some(current) if current != previous => {
+                changed.push(DirectorySnapshotChange { previous, current })
+            }
+            Some(_) => {}
+        }
+    }
+
+    (added, removed, changed)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::directory_sync::{DirectoryUserEmail, DirectoryUserState};
+    use crate::roles::{RoleSlug, RoleSlugObject};
+    use crate::{KnownOrUnknown, Timestamp, Timestamps};
+
+    use super::*;
+
+    fn timestamps() -> Timestamps {
+        Timestamps {
+            created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+            updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+        }
+    }
+
+    fn group(id: &str, name: &str) -> DirectoryGroup {
+        DirectoryGroup {
+            id: DirectoryGroupId::from(id),
+            idp_id: format!("idp_{id}"),
+            directory_id: DirectoryId::from("directory_123"),
+            organization_id: None,
+            name: name.to_string(),
+            timestamps: timestamps(),
+        }
+    }
+
+    fn user(id: &str, email: &str, groups: Vec<DirectoryGroup>) -> DirectoryUser {
+        DirectoryUser {
+            id: DirectoryUserId::from(id),
+            idp_id: format!("idp_{id}"),
+            directory_id: DirectoryId::from("directory_123"),
+            organization_id: None,
+            first_name: None,
+            last_name: None,
+            emails: vec![DirectoryUserEmail {
+                primary: Some(true),
+                r#type: Some("work".to_string()),
+                value: Some(email.to_string()),
+            }],
+            groups,
+            state: KnownOrUnknown::Known(DirectoryUserState::Active),
+            custom_attributes: HashMap::new(),
+            role: RoleSlugObject {
+                slug: RoleSlug::from("member"),
+            },
+            timestamps: timestamps(),
+        }
+    }
+
+    #[test]
+    fn it_looks_up_users_and_groups() {
+        let engineering = group("group_1", "Engineering");
+        let snapshot = DirectorySnapshot::new(
+            DirectoryId::from("directory_123"),
+            vec![
+                user(
+                    "user_1",
+                    "Marcelina@foo-corp.com",
+                    vec![engineering.clone()],
+                ),
+                user("user_2", "eric@foo-corp.com", vec![]),
+            ],
+            vec![engineering.clone()],
+        );
+
+        assert_eq!(
+            snapshot
+                .user_by_email("marcelina@FOO-CORP.com")
+                .map(|user| &user.id),
+            Some(&DirectoryUserId::from("user_1"))
+        );
+        assert_eq!(
+            snapshot.user_by_idp_id("idp_user_2").map(|user| &user.id),
+            Some(&DirectoryUserId::from("user_2"))
+        );
+        assert_eq!(snapshot.group_by_idp_id("idp_group_1"), Some(&engineering));
+        assert_eq!(
+            snapshot
+                .group_members(&engineering.id)
+                .map(|user| &user.id)
+                .collect::<Vec<_>>(),
+            vec![&DirectoryUserId::from("user_1")]
+        );
+        assert_eq!(
+            snapshot
+                .user_groups(&DirectoryUserId::from("user_1"))
+                .collect::<Vec<_>>(),
+            vec![&engineering]
+        );
+    }
+
+    #[test]
+    fn it_diffs_two_snapshots() {
+        let engineering = group("group_1", "Engineering");
+        let sales = group("group_2", "Sales");
+
+        let previous = DirectorySnapshot::new(
+            DirectoryId::from("directory_123"),
+            vec![
+                user("user_1", "marcelina@foo-corp.com", vec![]),
+                user("user_2", "eric@foo-corp.com", vec![]),
+            ],
+            vec![engineering.clone()],
+        );
+        let current = DirectorySnapshot::new(
+            DirectoryId::from("directory_123"),
+            vec![
+                user(
+                    "user_1",
+                    "marcelina@foo-corp.com",
+                    vec![engineering.clone()],
+                ),
+                user("user_3", "alan@foo-corp.com", vec![]),
+            ],
+            vec![engineering, sales.clone()],
+        );
+
+        let diff = previous.diff(&current);
+
+        assert_eq!(
+            diff.added_users
+                .iter()
+                .map(|user| &user.id)
+                .collect::<Vec<_>>(),
+            vec![&DirectoryUserId::from("user_3")]
+        );
+        assert_eq!(
+            diff.removed_users
+                .iter()
+                .map(|user| &user.id)
+                .collect::<Vec<_>>(),
+            vec![&DirectoryUserId::from("user_2")]
+        );
+        assert_eq!(
+            diff.changed_users
+                .iter()
+                .map(|change| &change.current.id)
+                .collect::<Vec<_>>(),
+            vec![&DirectoryUserId::from("user_1")]
+        );
+        assert_eq!(diff.added_groups, vec![&sales]);
+        assert!(diff.removed_groups.is_empty());
+        assert!(diff.changed_groups.is_empty());
+
+        assert!(current.diff(&current).is_empty());
+    }
+}
@@ -3,9 +3,11 @@
 //! [WorkOS Docs: Events Guide](https://workos.com/docs/events/guide)
 
 mod operations;
+mod subscriber;
 mod types;
 
 pub use operations::*;
+pub use subscriber::*;
 pub use types::*;
 
 use crate::WorkOs;
@@ -22,4 +24,9 @@ impl<'a> Events<'a> {
     pub fn new(workos: &'a WorkOs) -> Self {
         Self { workos }
     }
+
+    /// Returns an [`EventSubscriber`] that polls for new events using the provided options.
+    pub fn subscribe(&self, options: EventSubscriberOptions<'a>) -> EventSubscriber<'a> {
+        EventSubscriber::new(self.workos, options)
+    }
 }
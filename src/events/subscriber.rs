@@ -0,0 +1,558 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+use futures_util::{StreamExt, future, stream};
+use thiserror::Error;
+
+use crate::events::{Event, EventFilters, EventId, ListEvents, ListEventsParams};
+use crate::organizations::OrganizationId;
+use crate::{PaginationOrder, PaginationParams, WorkOs, WorkOsError};
+
+/// How an [`EventSubscriber`] dispatches the events of a page to the handler.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DispatchMode {
+    /// Events are handled one at a time, in the order they occurred.
+    #[default]
+    Sequential,
+
+    /// Events of different organizations are handled concurrently.
+    ///
+    /// Events belonging to the same organization are still handled one at a time, in the order they occurred.
+    /// Events which do not belong to an organization (e.g. `user.created`) are handled on their own: every event
+    /// before them is handled first, and the events after them are only handled once they are, so that e.g.
+    /// `organization_membership.created` is never handled before the `user.created` it refers to.
+    /// When the handler fails for one organization, the events of the other organizations that are already
+    /// being handled run to completion before the first error is returned.
+    PerOrganization {
+        /// The maximum number of organizations whose events are handled at the same time.
+        max_concurrency: NonZeroUsize,
+    },
+}
+
+/// The options for [`Events::subscribe`](crate::events::Events::subscribe).
+#[derive(Debug, Default)]
+pub struct EventSubscriberOptions<'a> {
+    /// Filter to only receive events of particular types.
+    pub events: EventFilters,
+
+    /// Filter to only receive events belonging to a specific organization.
+    pub organization_id: Option<&'a OrganizationId>,
+
+    /// The ID of the last handled event. Only events after this one are received.
+    pub after: Option<EventId>,
+
+    /// Upper limit on the number of events to fetch per poll, between 1 and 100.
+    pub limit: Option<u8>,
+
+    /// How events are dispatched to the handler.
+    pub dispatch_mode: DispatchMode,
+}
+
+/// An error returned from [`EventSubscriber::poll`].
+#[derive(Debug, Error)]
+pub enum EventSubscriberError<E> {
+    /// List events error.
+    #[error(transparent)]
+    ListEvents(#[from] WorkOsError<()>),
+
+    /// The handler failed to handle an event.
+    #[error("handler failed for event {event_id}")]
+    Handler {
+        /// The ID of the event that failed.
+        event_id: EventId,
+
+        /// The error returned by the handler.
+        error: E,
+    },
+}
+
+/// Event subscriber.
+///
+/// Polls the Events API and dispatches new events to a handler, keeping track of the cursor.
+pub struct EventSubscriber<'a> {
+    workos: &'a WorkOs,
+    events: EventFilters,
+    organization_id: Option<&'a OrganizationId>,
+    after: Option<EventId>,
+    limit: Option<u8>,
+    dispatch_mode: DispatchMode,
+}
+
+impl<'a> EventSubscriber<'a> {
+    pub(crate) fn new(workos: &'a WorkOs, options: EventSubscriberOptions<'a>) -> Self {
+        Self {
+            workos,
+            events: options.events,
+            organization_id: options.organization_id,
+            after: options.after,
+            limit: options.limit,
+            dispatch_mode: options.dispatch_mode,
+        }
+    }
+
+    /// The ID of the last event that was successfully handled, if any.
+    ///
+    /// Persist this to resume the subscription later using [`EventSubscriberOptions::after`].
+    pub fn cursor(&self) -> Option<&EventId> {
+        self.after.as_ref()
+    }
+
+    /// Fetches the next page of events and dispatches them to the handler.
+    ///
+    /// The cursor is only advanced when every event of the page was handled successfully,
+    /// so a failed page will be received again on the next poll.
+    ///
+    /// Returns the number of events that were handled.
+    pub async fn poll<H, F, E>(&mut self, handler: H) -> Result<usize, EventSubscriberError<E>>
+    where
+        H: Fn(Event) -> F,
+        F: Future<Output = Result<(), E>>,
+    {
+        self.workos.throttle().await;
+
+        let page = self
+            .workos
+            .events()
+            .list_events(&ListEventsParams {
+                pagination: PaginationParams {
+                    order: &PaginationOrder::Asc,
+                    after: self.after.as_deref().map(String::as_str),
+                    before: None,
+                    limit: self.limit,
+                },
+                events: self.events.clone(),
+                organization_id: self.organization_id,
+                range_start: None,
+                range_end: None,
+            })
+            .await?;
+
+        let Some(last_event_id) = page.data.last().map(|event| event.id.clone()) else {
+            return Ok(0);
+        };
+        let count = page.data.len();
+
+        match self.dispatch_mode {
+            DispatchMode::Sequential => {
+                dispatch_in_order(page.data, &handler).await?;
+            }
+            DispatchMode::PerOrganization { max_concurrency } => {
+                // Events without an organization act as barriers, so that e.g. a `user.created` event is
+                // handled after the events before it and before the organization events after it.
+                let mut segments: Vec<Vec<Event>> = Vec::new();
+                for event in page.data {
+                    let has_organization = event.data.organization_id().is_some();
+                    match segments.last_mut() {
+                        Some(segment)
+                            if segment[0].data.organization_id().is_some() == has_organization =>
+                        {
+                            segment.push(event)
+                        }
+                        _ => segments.push(vec![event]),
+                    }
+                }
+
+                for events in segments {
+                    if events[0].data.organization_id().is_some() {
+                        dispatch_per_organization(events, &handler, max_concurrency).await?;
+                    } else {
+                        dispatch_in_order(events, &handler).await?;
+                    }
+                }
+            }
+        }
+
+        self.after = Some(last_event_id);
+
+        Ok(count)
+    }
+}
+
+async fn dispatch_in_order<H, F, E>(
+    events: Vec<Event>,
+    handler: &H,
+) -> Result<(), EventSubscriberError<E>>
+where
+    H: Fn(Event) -> F,
+    F: Future<Output = Result<(), E>>,
+{
+    for event in events {
+        let event_id = event.id.clone();
+
+        handler(event)
+            .await
+            .map_err(|error| EventSubscriberError::Handler { event_id, error })?;
+    }
+
+    Ok(())
+}
+
+async fn dispatch_per_organization<H, F, E>(
+    events: Vec<Event>,
+    handler: &H,
+    max_concurrency: NonZeroUsize,
+) -> Result<(), EventSubscriberError<E>>
+where
+    H: Fn(Event) -> F,
+    F: Future<Output = Result<(), E>>,
+{
+    let mut groups: BTreeMap<Option<OrganizationId>, Vec<Event>> = BTreeMap::new();
+    for event in events {
+        groups
+            .entry(event.data.organization_id().cloned())
+            .or_default()
+            .push(event);
+    }
+
+    // Once a group fails no new groups are started, but the groups already in flight
+    // are not cancelled halfway through their handler.
+    let first_error = Mutex::new(None);
+    stream::iter(groups.into_values())
+        .take_while(|_| {
+            future::ready(
+                first_error
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .is_none(),
+            )
+        })
+        .for_each_concurrent(max_concurrency.get(), |events| {
+            let first_error = &first_error;
+            async move {
+                if let Err(error) = dispatch_in_order(events, handler).await {
+                    first_error
+                        .lock()
+                        .unwrap_or_else(|err| err.into_inner())
+                        .get_or_insert(error);
+                }
+            }
+        })
+        .await;
+
+    match first_error
+        .into_inner()
+        .unwrap_or_else(|err| err.into_inner())
+    {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use mockito::Matcher;
+    use serde_json::{Value, json};
+
+    use crate::ApiKey;
+
+    use super::*;
+
+    fn organization_event(id: &str, organization_id: &str) -> Value {
+        json!({
+            "object": "event",
+            "id": id,
+            "event": "organization.updated",
+            "data": {
+                "object": "organization",
+                "id": organization_id,
+                "name": "Foo Corp",
+                "allow_profiles_outside_organization": false,
+                "domains": [],
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z"
+            },
+            "created_at": "2023-06-09T18:12:01.837Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn it_preserves_the_order_of_events_per_organization() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded("order".to_string(), "asc".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "object": "list",
+                    "data": [
+                        organization_event("event_1", "org_a"),
+                        organization_event("event_2", "org_b"),
+                        organization_event("event_3", "org_a"),
+                        organization_event("event_4", "org_b"),
+                        organization_event("event_5", "org_a"),
+                    ],
+                    "list_metadata": {
+                        "after": "event_5"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let handled = Arc::new(Mutex::new(Vec::new()));
+
+        let mut subscriber = workos.events().subscribe(EventSubscriberOptions {
+            dispatch_mode: DispatchMode::PerOrganization {
+                max_concurrency: NonZeroUsize::new(2).unwrap(),
+            },
+            ..Default::default()
+        });
+
+        let count = subscriber
+            .poll(|event| {
+                let handled = handled.clone();
+                async move {
+                    handled
+                        .lock()
+                        .unwrap()
+                        .push((event.data.organization_id().cloned(), event.id.to_string()));
+                    Ok::<(), ()>(())
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(count, 5);
+        assert_eq!(subscriber.cursor(), Some(&EventId::from("event_5")));
+
+        let handled = handled.lock().unwrap();
+        let order_for = |organization_id: &str| {
+            handled
+                .iter()
+                .filter(|(id, _)| id.as_deref().map(String::as_str) == Some(organization_id))
+                .map(|(_, event_id)| event_id.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(order_for("org_a"), vec!["event_1", "event_3", "event_5"]);
+        assert_eq!(order_for("org_b"), vec!["event_2", "event_4"]);
+    }
+
+    #[tokio::test]
+    async fn it_does_not_advance_the_cursor_when_the_handler_fails() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/events")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("order".to_string(), "asc".to_string()),
+                Matcher::UrlEncoded("after".to_string(), "event_0".to_string()),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "object": "list",
+                    "data": [
+                        organization_event("event_1", "org_a"),
+                        organization_event("event_2", "org_a"),
+                    ],
+                    "list_metadata": {
+                        "after": "event_2"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut subscriber = workos.events().subscribe(EventSubscriberOptions {
+            after: Some(EventId::from("event_0")),
+            ..Default::default()
+        });
+
+        let result = subscriber
+            .poll(|event| async move {
+                if event.id == EventId::from("event_2") {
+                    Err("failed")
+                } else {
+                    Ok(())
+                }
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(EventSubscriberError::Handler { event_id, error: "failed" })
+                if event_id == EventId::from("event_2")
+        ));
+        assert_eq!(subscriber.cursor(), Some(&EventId::from("event_0")));
+    }
+
+    #[tokio::test]
+    async fn it_lets_other_organizations_finish_when_the_handler_fails() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded("order".to_string(), "asc".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "object": "list",
+                    "data": [
+                        organization_event("event_1", "org_a"),
+                        organization_event("event_2", "org_b"),
+                        organization_event("event_3", "org_b"),
+                    ],
+                    "list_metadata": {
+                        "after": "event_3"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let handled = Arc::new(Mutex::new(Vec::new()));
+
+        let mut subscriber = workos.events().subscribe(EventSubscriberOptions {
+            dispatch_mode: DispatchMode::PerOrganization {
+                max_concurrency: NonZeroUsize::new(2).unwrap(),
+            },
+            ..Default::default()
+        });
+
+        let result = subscriber
+            .poll(|event| {
+                let handled = handled.clone();
+                async move {
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    if event.id == EventId::from("event_1") {
+                        return Err("failed");
+                    }
+
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    handled.lock().unwrap().push(event.id.to_string());
+                    Ok(())
+                }
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(EventSubscriberError::Handler { event_id, error: "failed" })
+                if event_id == EventId::from("event_1")
+        ));
+        assert_eq!(*handled.lock().unwrap(), vec!["event_2", "event_3"]);
+        assert_eq!(subscriber.cursor(), None);
+    }
+
+    #[tokio::test]
+    async fn it_handles_events_without_an_organization_as_a_barrier() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded("order".to_string(), "asc".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "object": "list",
+                    "data": [
+                        organization_event("event_1", "org_b"),
+                        {
+                            "object": "event",
+                            "id": "event_2",
+                            "event": "user.created",
+                            "data": {
+                                "object": "user",
+                                "id": "user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E",
+                                "email": "marcelina.davis@example.com",
+                                "first_name": "Marcelina",
+                                "last_name": "Davis",
+                                "email_verified": true,
+                                "profile_picture_url": null,
+                                "last_sign_in_at": null,
+                                "external_id": null,
+                                "metadata": {},
+                                "created_at": "2021-06-25T19:07:33.155Z",
+                                "updated_at": "2021-06-25T19:07:33.155Z"
+                            },
+                            "created_at": "2023-06-09T18:12:01.837Z"
+                        },
+                        {
+                            "object": "event",
+                            "id": "event_3",
+                            "event": "organization_membership.created",
+                            "data": {
+                                "object": "organization_membership",
+                                "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                                "user_id": "user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E",
+                                "organization_id": "org_a",
+                                "role": {
+                                    "slug": "member"
+                                },
+                                "status": "active",
+                                "created_at": "2021-06-25T19:07:33.155Z",
+                                "updated_at": "2021-06-25T19:07:33.155Z"
+                            },
+                            "created_at": "2023-06-09T18:12:01.837Z"
+                        },
+                    ],
+                    "list_metadata": {
+                        "after": "event_3"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let handled = Arc::new(Mutex::new(Vec::new()));
+
+        let mut subscriber = workos.events().subscribe(EventSubscriberOptions {
+            dispatch_mode: DispatchMode::PerOrganization {
+                max_concurrency: NonZeroUsize::new(2).unwrap(),
+            },
+            ..Default::default()
+        });
+
+        let count = subscriber
+            .poll(|event| {
+                let handled = handled.clone();
+                async move {
+                    if event.id == EventId::from("event_1") {
+                        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                    }
+
+                    handled.lock().unwrap().push(event.id.to_string());
+                    Ok::<(), ()>(())
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            *handled.lock().unwrap(),
+            vec!["event_1", "event_2", "event_3"]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 mod event;
 mod events;
+mod workos_event;
 
 pub use event::*;
 pub use events::*;
+pub use workos_event::*;
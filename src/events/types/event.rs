@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
-use crate::{Timestamp, events::*};
+use crate::{Timestamp, events::*, organizations::OrganizationId};
 
 /// The ID of an [`Event`].
 #[derive(
@@ -529,6 +529,178 @@ pub enum EventData {
     UserUpdated(UserUpdatedEvent),
 }
 
+impl EventData {
+    /// The type of the event.
+    pub fn name(&self) -> EventName {
+        match self {
+            EventData::AuthenticationEmailVerificationFailed(_) => {
+                EventName::AuthenticationEmailVerificationFailed
+            }
+            EventData::AuthenticationEmailVerificationSucceeded(_) => {
+                EventName::AuthenticationEmailVerificationSucceeded
+            }
+            EventData::AuthenticationMagicAuthFailed(_) => EventName::AuthenticationMagicAuthFailed,
+            EventData::AuthenticationMagicAuthSucceeded(_) => {
+                EventName::AuthenticationMagicAuthSucceeded
+            }
+            EventData::AuthenticationMfaFailed(_) => EventName::AuthenticationMfaFailed,
+            EventData::AuthenticationMfaSucceeded(_) => EventName::AuthenticationMfaSucceeded,
+            EventData::AuthenticationOauthFailed(_) => EventName::AuthenticationOauthFailed,
+            EventData::AuthenticationOauthSucceeded(_) => EventName::AuthenticationOauthSucceeded,
+            EventData::AuthenticationPasswordFailed(_) => EventName::AuthenticationPasswordFailed,
+            EventData::AuthenticationPasswordSucceeded(_) => {
+                EventName::AuthenticationPasswordSucceeded
+            }
+            EventData::AuthenticationPasskeyFailed(_) => EventName::AuthenticationPasskeyFailed,
+            EventData::AuthenticationPasskeySucceeded(_) => {
+                EventName::AuthenticationPasskeySucceeded
+            }
+            EventData::AuthenticationSsoFailed(_) => EventName::AuthenticationSsoFailed,
+            EventData::AuthenticationSsoSucceeded(_) => EventName::AuthenticationSsoSucceeded,
+            EventData::AuthenticationRadarRiskDetected(_) => {
+                EventName::AuthenticationRadarRiskDetected
+            }
+            EventData::ConnectionActivated(_) => EventName::ConnectionActivated,
+            EventData::ConnectionDeactivated(_) => EventName::ConnectionDeactivated,
+            EventData::ConnectionDeleted(_) => EventName::ConnectionDeleted,
+            EventData::ConnectionSamlCertificateRenewed(_) => {
+                EventName::ConnectionSamlCertificateRenewed
+            }
+            EventData::ConnectionSamlCertificateRenewalRequired(_) => {
+                EventName::ConnectionSamlCertificateRenewalRequired
+            }
+            EventData::DsyncActivated(_) => EventName::DsyncActivated,
+            EventData::DsyncDeleted(_) => EventName::DsyncDeleted,
+            EventData::DsyncGroupCreated(_) => EventName::DsyncGroupCreated,
+            EventData::DsyncGroupDeleted(_) => EventName::DsyncGroupDeleted,
+            EventData::DsyncGroupUpdated(_) => EventName::DsyncGroupUpdated,
+            EventData::DsyncGroupUserAdded(_) => EventName::DsyncGroupUserAdded,
+            EventData::DsyncGroupUserRemoved(_) => EventName::DsyncGroupUserRemoved,
+            EventData::DsyncUserCreated(_) => EventName::DsyncUserCreated,
+            EventData::DsyncUserDeleted(_) => EventName::DsyncUserDeleted,
+            EventData::DsyncUserUpdated(_) => EventName::DsyncUserUpdated,
+            EventData::EmailVerificationCreated(_) => EventName::EmailVerificationCreated,
+            EventData::InvitationAccepted(_) => EventName::InvitationAccepted,
+            EventData::InvitationCreated(_) => EventName::InvitationCreated,
+            EventData::InvitationRevoked(_) => EventName::InvitationRevoked,
+            EventData::MagicAuthCreated(_) => EventName::MagicAuthCreated,
+            EventData::OrganizationCreated(_) => EventName::OrganizationCreated,
+            EventData::OrganizationUpdated(_) => EventName::OrganizationUpdated,
+            EventData::OrganizationDeleted(_) => EventName::OrganizationDeleted,
+            EventData::OrganizationDomainCreated(_) => EventName::OrganizationDomainCreated,
+            EventData::OrganizationDomainUpdated(_) => EventName::OrganizationDomainUpdated,
+            EventData::OrganizationDomainDeleted(_) => EventName::OrganizationDomainDeleted,
+            EventData::OrganizationDomainVerified(_) => EventName::OrganizationDomainVerified,
+            EventData::OrganizationDomainVerificationFailed(_) => {
+                EventName::OrganizationDomainVerificationFailed
+            }
+            EventData::OrganizationMembershipCreated(_) => EventName::OrganizationMembershipCreated,
+            EventData::OrganizationMembershipDeleted(_) => EventName::OrganizationMembershipDeleted,
+            EventData::OrganizationMembershipUpdated(_) => EventName::OrganizationMembershipUpdated,
+            EventData::PasswordResetCreated(_) => EventName::PasswordResetCreated,
+            EventData::PasswordResetSucceeded(_) => EventName::PasswordResetSucceeded,
+            EventData::RoleCreated(_) => EventName::RoleCreated,
+            EventData::RoleDeleted(_) => EventName::RoleDeleted,
+            EventData::RoleUpdated(_) => EventName::RoleUpdated,
+            EventData::SessionCreated(_) => EventName::SessionCreated,
+            EventData::SessionRevoked(_) => EventName::SessionRevoked,
+            EventData::UserCreated(_) => EventName::UserCreated,
+            EventData::UserDeleted(_) => EventName::UserDeleted,
+            EventData::UserUpdated(_) => EventName::UserUpdated,
+        }
+    }
+
+    /// The ID of the organization the event belongs to, if any.
+    ///
+    /// User-level events (e.g. `user.created`) are not organization specific and return [`None`].
+    pub fn organization_id(&self) -> Option<&OrganizationId> {
+        match self {
+            EventData::AuthenticationEmailVerificationFailed(_)
+            | EventData::AuthenticationEmailVerificationSucceeded(_)
+            | EventData::AuthenticationMagicAuthFailed(_)
+            | EventData::AuthenticationMagicAuthSucceeded(_)
+            | EventData::AuthenticationMfaFailed(_)
+            | EventData::AuthenticationMfaSucceeded(_)
+            | EventData::AuthenticationOauthFailed(_)
+            | EventData::AuthenticationOauthSucceeded(_)
+            | EventData::AuthenticationPasswordFailed(_)
+            | EventData::AuthenticationPasswordSucceeded(_)
+            | EventData::AuthenticationPasskeyFailed(_)
+            | EventData::AuthenticationPasskeySucceeded(_)
+            | EventData::AuthenticationSsoFailed(_)
+            | EventData::AuthenticationSsoSucceeded(_)
+            | EventData::AuthenticationRadarRiskDetected(_)
+            | EventData::EmailVerificationCreated(_)
+            | EventData::MagicAuthCreated(_)
+            | EventData::PasswordResetCreated(_)
+            | EventData::PasswordResetSucceeded(_)
+            | EventData::RoleCreated(_)
+            | EventData::RoleDeleted(_)
+            | EventData::RoleUpdated(_)
+            | EventData::UserCreated(_)
+            | EventData::UserDeleted(_)
+            | EventData::UserUpdated(_) => None,
+            EventData::ConnectionActivated(ConnectionActivatedEvent(connection))
+            | EventData::ConnectionDeactivated(ConnectionDeactivatedEvent(connection))
+            | EventData::ConnectionDeleted(ConnectionDeletedEvent(connection)) => {
+                connection.organization_id.as_ref()
+            }
+            EventData::ConnectionSamlCertificateRenewed(event) => {
+                event.connection.organization_id.as_ref()
+            }
+            EventData::ConnectionSamlCertificateRenewalRequired(event) => {
+                event.connection.organization_id.as_ref()
+            }
+            EventData::DsyncActivated(DsyncActivatedEvent(directory))
+            | EventData::DsyncDeleted(DsyncDeletedEvent(directory)) => {
+                directory.organization_id.as_ref()
+            }
+            EventData::DsyncGroupCreated(DsyncGroupCreatedEvent(group))
+            | EventData::DsyncGroupDeleted(DsyncGroupDeletedEvent(group))
+            | EventData::DsyncGroupUpdated(DsyncGroupUpdatedEvent(group)) => {
+                group.organization_id.as_ref()
+            }
+            EventData::DsyncGroupUserAdded(event) => event.group.organization_id.as_ref(),
+            EventData::DsyncGroupUserRemoved(event) => event.group.organization_id.as_ref(),
+            EventData::DsyncUserCreated(DsyncUserCreatedEvent(user))
+            | EventData::DsyncUserDeleted(DsyncUserDeletedEvent(user))
+            | EventData::DsyncUserUpdated(DsyncUserUpdatedEvent(user)) => {
+                user.organization_id.as_ref()
+            }
+            EventData::InvitationAccepted(InvitationAcceptedEvent(invitation))
+            | EventData::InvitationCreated(InvitationCreatedEvent(invitation))
+            | EventData::InvitationRevoked(InvitationRevokedEvent(invitation)) => {
+                invitation.organization_id.as_ref()
+            }
+            EventData::OrganizationCreated(OrganizationCreatedEvent(organization))
+            | EventData::OrganizationUpdated(OrganizationUpdatedEvent(organization))
+            | EventData::OrganizationDeleted(OrganizationDeletedEvent(organization)) => {
+                Some(&organization.id)
+            }
+            EventData::OrganizationDomainCreated(OrganizationDomainCreatedEvent(domain))
+            | EventData::OrganizationDomainUpdated(OrganizationDomainUpdatedEvent(domain))
+            | EventData::OrganizationDomainDeleted(OrganizationDomainDeletedEvent(domain))
+            | EventData::OrganizationDomainVerified(OrganizationDomainVerifiedEvent(domain))
+            | EventData::OrganizationDomainVerificationFailed(
+                OrganizationDomainVerificationFailedEvent(domain),
+            ) => Some(&domain.organization_id),
+            EventData::OrganizationMembershipCreated(OrganizationMembershipCreatedEvent(
+                membership,
+            ))
+            | EventData::OrganizationMembershipDeleted(OrganizationMembershipDeletedEvent(
+                membership,
+            ))
+            | EventData::OrganizationMembershipUpdated(OrganizationMembershipUpdatedEvent(
+                membership,
+            )) => Some(&membership.organization_id),
+            EventData::SessionCreated(SessionCreatedEvent(session))
+            | EventData::SessionRevoked(SessionRevokedEvent(session)) => {
+                session.organization_id.as_ref()
+            }
+        }
+    }
+}
+
 /// [WorkOS Docs: Event](https://workos.com/docs/reference/event)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Event {
@@ -0,0 +1,49 @@
+use crate::Timestamp;
+use crate::events::{Event, EventData, EventName};
+use crate::organizations::OrganizationId;
+
+/// An event received from WorkOS, regardless of how it was delivered.
+///
+/// Implemented by [`Event`] (received from the Events API) and `webhooks::Webhook`
+/// (received as a webhook), so a single handler can serve both delivery mechanisms.
+pub trait WorkOsEvent {
+    /// Unique identifier of the event or webhook.
+    fn id(&self) -> &str;
+
+    /// Event data.
+    fn data(&self) -> &EventData;
+
+    /// Consumes the event and returns the event data.
+    fn into_data(self) -> EventData;
+
+    /// Timestamp of when the event occurred.
+    fn created_at(&self) -> &Timestamp;
+
+    /// The type of the event.
+    fn name(&self) -> EventName {
+        self.data().name()
+    }
+
+    /// The ID of the organization the event belongs to, if any.
+    fn organization_id(&self) -> Option<&OrganizationId> {
+        self.data().organization_id()
+    }
+}
+
+impl WorkOsEvent for Event {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn data(&self) -> &EventData {
+        &self.data
+    }
+
+    fn into_data(self) -> EventData {
+        self.data
+    }
+
+    fn created_at(&self) -> &Timestamp {
+        &self.created_at
+    }
+}
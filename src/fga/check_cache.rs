@@ -0,0 +1,451 @@
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::WorkOsResult;
+use crate::fga::{
+    Check, CheckError, CheckParams, CheckResponse, Fga, FgaResource, FgaSubject, WarrantToken,
+};
+
+type CheckKey = (FgaSubject, String, FgaResource);
+
+struct CachedCheck {
+    response: CheckResponse,
+    /// The warrant token the check was made with.
+    warrant_token: Option<WarrantToken>,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+struct CheckCacheState {
+    warrant_token: Option<WarrantToken>,
+    generation: u64,
+    entries: HashMap<CheckKey, CachedCheck>,
+    /// The keys in the order they were inserted. All entries share the same TTL, so this is
+    /// also the order in which they expire.
+    expiry_order: VecDeque<(CheckKey, Instant)>,
+}
+
+impl CheckCacheState {
+    fn insert(
+        &mut self,
+        key: CheckKey,
+        response: CheckResponse,
+        warrant_token: Option<WarrantToken>,
+        ttl: Duration,
+        max_entries: usize,
+    ) {
+        let now = Instant::now();
+
+        while let Some((_, expires_at)) = self.expiry_order.front() {
+            if *expires_at > now && self.entries.len() < max_entries {
+                break;
+            }
+
+            let (key, expires_at) = self.expiry_order.pop_front().unwrap();
+
+            // The entry may have been replaced since, in which case it is not evicted.
+            if self
+                .entries
+                .get(&key)
+                .is_some_and(|cached| cached.expires_at == expires_at)
+            {
+                self.entries.remove(&key);
+            }
+        }
+
+        let expires_at = now + ttl;
+
+        self.expiry_order.push_back((key.clone(), expires_at));
+        self.entries.insert(
+            key,
+            CachedCheck {
+                response,
+                warrant_token,
+                expires_at,
+            },
+        );
+    }
+
+    fn clear(&mut self) {
+        self.generation += 1;
+        self.entries.clear();
+        self.expiry_order.clear();
+    }
+}
+
+/// A short-lived cache of [`Check`] results, keyed by subject, relation and resource.
+///
+/// A check that passes a warrant token the cache has not seen before bypasses the cache and
+/// clears it, so checks made after a write (with the token returned by that write) always
+/// observe it. A check that passes the most recently seen warrant token is only served results
+/// of checks made with that same token, as results of checks without a token may have been
+/// computed before the write. Checks without a warrant token are served any cached result until
+/// the entry expires.
+///
+/// Expired entries are evicted when new results are cached. When the cache is full, the entries
+/// closest to expiring are evicted first.
+pub struct FgaCheckCache {
+    ttl: Duration,
+    max_entries: NonZeroUsize,
+    state: Mutex<CheckCacheState>,
+}
+
+impl FgaCheckCache {
+    /// Returns a new [`FgaCheckCache`] whose entries expire after the given duration, holding
+    /// at most `max_entries` results.
+    pub fn new(ttl: Duration, max_entries: NonZeroUsize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            state: Mutex::new(CheckCacheState::default()),
+        }
+    }
+
+    /// Checks whether a subject has a relation on a resource, using a cached result when possible.
+    pub async fn check(
+        &self,
+        fga: &Fga<'_>,
+        params: &CheckParams<'_>,
+    ) -> WorkOsResult<CheckResponse, CheckError> {
+        let key = (
+            params.subject.clone(),
+            params.relation.to_string(),
+            params.resource.clone(),
+        );
+
+        let generation = {
+            let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+            match params.warrant_token {
+                Some(warrant_token) if state.warrant_token.as_ref() != Some(warrant_token) => {
+                    state.warrant_token = Some(warrant_token.clone());
+                    state.clear();
+                }
+                _ => match state.entries.get(&key) {
+                    Some(cached) if cached.expires_at <= Instant::now() => {
+                        state.entries.remove(&key);
+                    }
+                    // A result computed without the warrant token may predate the write.
+                    Some(cached)
+                        if params.warrant_token.is_none()
+                            || cached.warrant_token.as_ref() == params.warrant_token =>
+                    {
+                        return Ok(cached.response.clone());
+                    }
+                    _ => {}
+                },
+            }
+
+            state.generation
+        };
+
+        let response = fga.check(params).await?;
+
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        // Results of checks that started before the cache was invalidated may predate the
+        // write that invalidated it, so they are not cached.
+        if state.generation == generation {
+            state.insert(
+                key,
+                response.clone(),
+                params.warrant_token.cloned(),
+                self.ttl,
+                self.max_entries.get(),
+            );
+        }
+
+        Ok(response)
+    }
+
+    /// Removes all cached results.
+    pub fn invalidate(&self) {
+        self.state
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+    use tokio;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn resource() -> FgaResource {
+        FgaResource {
+            resource_type: "report".to_string(),
+            resource_id: "avk2837".to_string(),
+        }
+    }
+
+    fn subject() -> FgaSubject {
+        FgaSubject {
+            resource_type: "user".to_string(),
+            resource_id: "user_01HXYZ".to_string(),
+            relation: None,
+        }
+    }
+
+    fn len(cache: &FgaCheckCache) -> usize {
+        cache.state.lock().unwrap().entries.len()
+    }
+
+    #[tokio::test]
+    async fn it_serves_repeated_checks_from_the_cache() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let mock = server
+            .mock("POST", "/fga/v1/check")
+            .with_status(200)
+            .with_body(json!({ "result": "authorized", "is_implicit": false }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache = FgaCheckCache::new(Duration::from_secs(60), NonZeroUsize::new(100).unwrap());
+        let (resource, subject) = (resource(), subject());
+        let params = CheckParams {
+            resource: &resource,
+            relation: "viewer",
+            subject: &subject,
+            warrant_token: None,
+        };
+
+        for _ in 0..2 {
+            let response = cache.check(&workos.fga(), &params).await.unwrap();
+
+            assert!(response.is_authorized());
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_bypasses_the_cache_for_a_new_warrant_token() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let before_write = server
+            .mock("POST", "/fga/v1/check")
+            .with_status(200)
+            .with_body(json!({ "result": "not_authorized", "is_implicit": false }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let after_write = server
+            .mock("POST", "/fga/v1/check")
+            .match_header("Warrant-Token", "token_1")
+            .with_status(200)
+            .with_body(json!({ "result": "authorized", "is_implicit": false }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache = FgaCheckCache::new(Duration::from_secs(60), NonZeroUsize::new(100).unwrap());
+        let (resource, subject) = (resource(), subject());
+        let warrant_token = WarrantToken::from("token_1");
+
+        let mut params = CheckParams {
+            resource: &resource,
+            relation: "viewer",
+            subject: &subject,
+            warrant_token: None,
+        };
+
+        assert!(
+            !cache
+                .check(&workos.fga(), &params)
+                .await
+                .unwrap()
+                .is_authorized()
+        );
+
+        params.warrant_token = Some(&warrant_token);
+
+        assert!(
+            cache
+                .check(&workos.fga(), &params)
+                .await
+                .unwrap()
+                .is_authorized()
+        );
+        assert!(
+            cache
+                .check(&workos.fga(), &params)
+                .await
+                .unwrap()
+                .is_authorized()
+        );
+
+        before_write.assert_async().await;
+        after_write.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_serve_results_without_a_warrant_token_to_checks_with_one() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        // A stale replica that has not seen the write yet.
+        let without_token = server
+            .mock("POST", "/fga/v1/check")
+            .match_header("Warrant-Token", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body(json!({ "result": "not_authorized", "is_implicit": false }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+        let with_token = server
+            .mock("POST", "/fga/v1/check")
+            .match_header("Warrant-Token", "token_1")
+            .with_status(200)
+            .with_body(json!({ "result": "authorized", "is_implicit": false }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let cache = FgaCheckCache::new(Duration::from_secs(60), NonZeroUsize::new(100).unwrap());
+        let (resource, subject) = (resource(), subject());
+        let warrant_token = WarrantToken::from("token_1");
+
+        let check = |relation, warrant_token| {
+            let (cache, workos, resource, subject) = (&cache, &workos, &resource, &subject);
+            async move {
+                cache
+                    .check(
+                        &workos.fga(),
+                        &CheckParams {
+                            resource,
+                            relation,
+                            subject,
+                            warrant_token,
+                        },
+                    )
+                    .await
+                    .unwrap()
+                    .is_authorized()
+            }
+        };
+
+        assert!(!check("viewer", None).await);
+
+        // A write returned `token_1`.
+        assert!(check("viewer", Some(&warrant_token)).await);
+
+        assert!(!check("editor", None).await);
+        assert!(check("editor", Some(&warrant_token)).await);
+
+        without_token.assert_async().await;
+        with_token.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_evicts_expired_entries_of_other_keys() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("POST", "/fga/v1/check")
+            .with_status(200)
+            .with_body(json!({ "result": "authorized", "is_implicit": false }).to_string())
+            .create_async()
+            .await;
+
+        let cache = FgaCheckCache::new(Duration::from_millis(20), NonZeroUsize::new(100).unwrap());
+        let (resource, subject) = (resource(), subject());
+
+        cache
+            .check(
+                &workos.fga(),
+                &CheckParams {
+                    resource: &resource,
+                    relation: "viewer",
+                    subject: &subject,
+                    warrant_token: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        cache
+            .check(
+                &workos.fga(),
+                &CheckParams {
+                    resource: &resource,
+                    relation: "editor",
+                    subject: &subject,
+                    warrant_token: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(len(&cache), 1);
+    }
+
+    #[tokio::test]
+    async fn it_evicts_the_oldest_entry_when_full() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let mock = server
+            .mock("POST", "/fga/v1/check")
+            .with_status(200)
+            .with_body(json!({ "result": "authorized", "is_implicit": false }).to_string())
+            .expect(3)
+            .create_async()
+            .await;
+
+        let cache = FgaCheckCache::new(Duration::from_secs(60), NonZeroUsize::new(1).unwrap());
+        let (resource, subject) = (resource(), subject());
+
+        for relation in ["viewer", "editor", "viewer"] {
+            cache
+                .check(
+                    &workos.fga(),
+                    &CheckParams {
+                        resource: &resource,
+                        relation,
+                        subject: &subject,
+                        warrant_token: None,
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(len(&cache), 1);
+
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,5 @@
+mod resource;
+mod warrant_token;
+
+pub use resource::*;
+pub use warrant_token::*;
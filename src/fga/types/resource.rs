@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// A resource in the authorization model.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FgaResource {
+    /// The type of the resource.
+    pub resource_type: String,
+
+    /// The unique ID of the resource.
+    pub resource_id: String,
+}
+
+/// The subject of a warrant or check.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FgaSubject {
+    /// The type of the subject.
+    pub resource_type: String,
+
+    /// The unique ID of the subject.
+    pub resource_id: String,
+
+    /// The relation of the subject, when the subject is a group of resources (e.g. members of a team).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation: Option<String>,
+}
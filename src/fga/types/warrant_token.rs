@@ -0,0 +1,11 @@
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+
+/// A token identifying the state of the warrants at the time of a write.
+///
+/// Passing the token of a write to a subsequent check guarantees the check observes that write.
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct WarrantToken(String);
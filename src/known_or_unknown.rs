@@ -2,7 +2,12 @@ use serde::{Deserialize, Serialize};
 
 /// `KnownOrUnknown` is a type that respresents either a known value ([`Known`](KnownOrUnknown::Known))
 /// or an unknown value ([`Unknown`](KnownOrUnknown::Unknown)).
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// When the `strict` feature is enabled, deserializing a value that is not known fails instead of
+/// producing an [`Unknown`](KnownOrUnknown::Unknown). This is intended for integration tests that
+/// should catch changes to the WorkOS API early.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(not(feature = "strict"), derive(Deserialize))]
 #[serde(untagged)]
 pub enum KnownOrUnknown<K, U> {
     /// A known value.
@@ -11,3 +16,55 @@ pub enum KnownOrUnknown<K, U> {
     /// An unknown value.
     Unknown(U),
 }
+
+#[cfg(feature = "strict")]
+impl<'de, K, U> Deserialize<'de> for KnownOrUnknown<K, U>
+where
+    K: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        K::deserialize(deserializer).map(KnownOrUnknown::Known)
+    }
+}
+
+impl<K, U> AsRef<str> for KnownOrUnknown<K, U>
+where
+    K: AsRef<str>,
+    U: AsRef<str>,
+{
+    fn as_ref(&self) -> &str {
+        match self {
+            KnownOrUnknown::Known(known) => known.as_ref(),
+            KnownOrUnknown::Unknown(unknown) => unknown.as_ref(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "directory-sync"))]
+mod test {
+    use serde_json::json;
+
+    use crate::directory_sync::DirectoryType;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_known_values() {
+        let value: KnownOrUnknown<DirectoryType, String> =
+            serde_json::from_value(json!("azure scim v2.0")).unwrap();
+
+        assert_eq!(value, KnownOrUnknown::Known(DirectoryType::AzureScimV2_0))
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn it_rejects_unknown_values_in_strict_mode() {
+        let value =
+            serde_json::from_value::<KnownOrUnknown<DirectoryType, String>>(json!("UnknownType"));
+
+        assert!(value.is_err())
+    }
+}
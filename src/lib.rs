@@ -1,4 +1,8 @@
 //! Rust SDK for interacting with the [WorkOS](https://workos.com) API.
+//!
+//! Each API module is behind a Cargo feature of the same name in kebab case (e.g. `user-management`).
+//! All of them are enabled by default. With `default-features = false`, enable the modules you use
+//! explicitly, as no API module other than `organizations` and `organization_domains` is included.
 
 #![warn(missing_docs)]
 #![allow(deprecated)]
@@ -7,15 +11,31 @@ mod core;
 mod known_or_unknown;
 mod workos;
 
+#[cfg(feature = "audit-logs")]
+pub mod audit_logs;
+#[cfg(feature = "directory-sync")]
 pub mod directory_sync;
+#[cfg(feature = "events")]
 pub mod events;
+#[cfg(feature = "fga")]
+pub mod fga;
+#[cfg(feature = "mfa")]
 pub mod mfa;
 pub mod organization_domains;
 pub mod organizations;
+#[cfg(feature = "portal")]
 pub mod portal;
+#[cfg(feature = "roles")]
 pub mod roles;
+#[cfg(feature = "sso")]
 pub mod sso;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "user-management")]
 pub mod user_management;
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
+#[cfg(feature = "widgets")]
 pub mod widgets;
 
 pub use crate::core::*;
@@ -1,3 +1,4 @@
+use chrono::{TimeDelta, Utc};
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
@@ -30,6 +31,25 @@ pub struct AuthenticationChallenge {
     pub timestamps: Timestamps,
 }
 
+impl AuthenticationChallenge {
+    /// Whether the authentication challenge has expired.
+    ///
+    /// Challenges without an expiry, such as those for TOTP factors, never expire.
+    pub fn is_expired(&self) -> bool {
+        self.expires_in()
+            .is_some_and(|expires_in| expires_in <= TimeDelta::zero())
+    }
+
+    /// The time remaining until the authentication challenge expires.
+    ///
+    /// Returns [`None`] if the challenge does not expire and a zero duration if it has already expired.
+    pub fn expires_in(&self) -> Option<TimeDelta> {
+        self.expires_at
+            .as_ref()
+            .map(|expires_at| (expires_at.0 - Utc::now().fixed_offset()).max(TimeDelta::zero()))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
@@ -68,4 +88,64 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_reports_an_expired_challenge() {
+        let challenge = AuthenticationChallenge {
+            id: AuthenticationChallengeId::from("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5"),
+            authentication_factor_id: AuthenticationFactorId::from(
+                "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+            ),
+            expires_at: Timestamp::try_from("2022-02-15T15:36:53.279Z").ok(),
+            timestamps: Timestamps {
+                created_at: Timestamp::try_from("2022-02-15T15:26:53.274Z").unwrap(),
+                updated_at: Timestamp::try_from("2022-02-15T15:26:53.274Z").unwrap(),
+            },
+        };
+
+        assert!(challenge.is_expired());
+        assert_eq!(challenge.expires_in(), Some(TimeDelta::zero()));
+    }
+
+    #[test]
+    fn it_reports_a_pending_challenge() {
+        let expires_at = Utc::now().fixed_offset() + TimeDelta::minutes(10);
+
+        let challenge = AuthenticationChallenge {
+            id: AuthenticationChallengeId::from("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5"),
+            authentication_factor_id: AuthenticationFactorId::from(
+                "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+            ),
+            expires_at: Some(Timestamp(expires_at)),
+            timestamps: Timestamps {
+                created_at: Timestamp::try_from("2022-02-15T15:26:53.274Z").unwrap(),
+                updated_at: Timestamp::try_from("2022-02-15T15:26:53.274Z").unwrap(),
+            },
+        };
+
+        assert!(!challenge.is_expired());
+        assert!(
+            challenge
+                .expires_in()
+                .is_some_and(|expires_in| expires_in > TimeDelta::minutes(9))
+        );
+    }
+
+    #[test]
+    fn it_never_expires_a_challenge_without_an_expiry() {
+        let challenge = AuthenticationChallenge {
+            id: AuthenticationChallengeId::from("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5"),
+            authentication_factor_id: AuthenticationFactorId::from(
+                "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+            ),
+            expires_at: None,
+            timestamps: Timestamps {
+                created_at: Timestamp::try_from("2022-02-15T15:26:53.274Z").unwrap(),
+                updated_at: Timestamp::try_from("2022-02-15T15:26:53.274Z").unwrap(),
+            },
+        };
+
+        assert!(!challenge.is_expired());
+        assert_eq!(challenge.expires_in(), None);
+    }
 }
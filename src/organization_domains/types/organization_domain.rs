@@ -42,6 +42,16 @@ pub enum OrganizationDomainVerificationStrategy {
 #[from(forward)]
 pub struct OrganizationDomainVerificationToken(String);
 
+/// A DNS TXT record that verifies ownership of an [`OrganizationDomain`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DnsTxtRecord {
+    /// The fully qualified name of the record, e.g. `superapp-domain-verification-z3kjny.foo-corp.com`.
+    pub name: String,
+
+    /// The value of the record.
+    pub value: String,
+}
+
 /// [WorkOS Docs: Organization Domain](https://workos.com/docs/reference/organization-domain)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OrganizationDomain {
@@ -63,7 +73,82 @@ pub struct OrganizationDomain {
     /// Validation token to be used in DNS TXT record.
     pub verification_token: Option<OrganizationDomainVerificationToken>,
 
+    /// Prefix of the name of the DNS TXT record.
+    pub verification_prefix: Option<String>,
+
     /// The timestamps for the organization domain.
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
+
+impl OrganizationDomain {
+    /// The DNS TXT record that must be published to verify the domain.
+    ///
+    /// Returns `None` if the domain is not verified using DNS, or if the API did not return
+    /// the verification prefix and token.
+    pub fn dns_txt_record(&self) -> Option<DnsTxtRecord> {
+        if self.verification_strategy
+            != KnownOrUnknown::Known(OrganizationDomainVerificationStrategy::Dns)
+        {
+            return None;
+        }
+
+        let prefix = self.verification_prefix.as_ref()?;
+        let token = self.verification_token.as_ref()?;
+
+        Some(DnsTxtRecord {
+            name: format!("{prefix}.{}", self.domain),
+            value: token.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_the_dns_txt_record() {
+        let organization_domain: OrganizationDomain = serde_json::from_value(json!({
+            "object": "organization_domain",
+            "id": "org_domain_01HEJXJSTVEDT7T58BM70FMFET",
+            "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "domain": "foo-corp.com",
+            "state": "pending",
+            "verification_strategy": "dns",
+            "verification_token": "aW5HQ8Sgps1y3LQyrShsFRo3F",
+            "verification_prefix": "superapp-domain-verification-z3kjny",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            organization_domain.dns_txt_record(),
+            Some(DnsTxtRecord {
+                name: "superapp-domain-verification-z3kjny.foo-corp.com".to_string(),
+                value: "aW5HQ8Sgps1y3LQyrShsFRo3F".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_returns_no_dns_txt_record_for_manually_verified_domains() {
+        let organization_domain: OrganizationDomain = serde_json::from_value(json!({
+            "object": "organization_domain",
+            "id": "org_domain_01HEJXJSTVEDT7T58BM70FMFET",
+            "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "domain": "foo-corp.com",
+            "state": "verified",
+            "verification_strategy": "manual",
+            "verification_token": null,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        }))
+        .unwrap();
+
+        assert_eq!(organization_domain.dns_txt_record(), None);
+    }
+}
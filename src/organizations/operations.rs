@@ -1,5 +1,6 @@
 mod create_organization;
 mod delete_organization;
+mod for_each_organization;
 mod get_organization;
 mod get_organization_by_external_id;
 mod list_organizations;
@@ -7,6 +8,7 @@ mod update_organization;
 
 pub use create_organization::*;
 pub use delete_organization::*;
+pub use for_each_organization::*;
 pub use get_organization::*;
 pub use get_organization_by_external_id::*;
 pub use list_organizations::*;
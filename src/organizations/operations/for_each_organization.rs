@@ -0,0 +1,365 @@
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_util::{StreamExt, stream};
+
+use crate::organizations::{
+    ListOrganizations, ListOrganizationsParams, Organization, OrganizationId, Organizations,
+};
+use crate::{PaginationParams, RetryPolicy, WorkOsError, WorkOsResult, retry};
+
+/// The page size used when listing organizations in [`ForEachOrganization`].
+const PAGE_SIZE: u8 = 100;
+
+/// The parameters for [`ForEachOrganization`].
+#[derive(Clone, Debug)]
+pub struct ForEachOrganizationParams<'a> {
+    /// Only visit organizations with one of these domains.
+    pub domains: Option<Vec<&'a str>>,
+
+    /// The maximum number of organizations handled at the same time.
+    pub max_concurrency: NonZeroUsize,
+
+    /// How many times a failed request or handler is retried before giving up.
+    pub max_retries: u32,
+
+    /// The minimum delay before the first retry. The delay is doubled for each subsequent retry.
+    ///
+    /// When the WorkOS API requests a longer delay using the `Retry-After` header, that delay is
+    /// used instead.
+    pub retry_delay: Duration,
+}
+
+impl Default for ForEachOrganizationParams<'_> {
+    fn default() -> Self {
+        Self {
+            domains: None,
+            max_concurrency: NonZeroUsize::new(4).unwrap(),
+            max_retries: 3,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+impl ForEachOrganizationParams<'_> {
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_retries: self.max_retries,
+            retry_delay: self.retry_delay,
+        }
+    }
+}
+
+/// An organization for which the handler of [`ForEachOrganization`] failed.
+#[derive(Debug)]
+pub struct ForEachOrganizationFailure<E> {
+    /// The ID of the organization.
+    pub organization_id: OrganizationId,
+
+    /// The error returned by the handler.
+    pub error: E,
+}
+
+/// The outcome of [`ForEachOrganization`].
+#[derive(Debug)]
+pub struct ForEachOrganizationReport<E> {
+    /// The organizations that were handled successfully.
+    pub succeeded: Vec<OrganizationId>,
+
+    /// The organizations for which the handler failed.
+    pub failed: Vec<ForEachOrganizationFailure<E>>,
+}
+
+impl<E> ForEachOrganizationReport<E> {
+    /// Whether the handler succeeded for every organization.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Run a handler for every organization.
+#[async_trait]
+pub trait ForEachOrganization {
+    /// Runs the handler for every organization, e.g. to backfill metadata.
+    ///
+    /// Organizations are listed one page at a time and the handler runs concurrently for the
+    /// organizations of a page. Rate limited requests to list organizations are retried, waiting
+    /// for the delay requested by the WorkOS API.
+    ///
+    /// A handler error is retried when `retry_after` returns the delay to wait before retrying,
+    /// and is not retried when it returns `None`. For handlers that call the WorkOS API, pass
+    /// [`WorkOsError::retry_after`] to retry rate limited requests.
+    ///
+    /// A failing handler does not stop the other organizations from being handled; its error is
+    /// collected in the returned report. Failing to list organizations returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use workos::WorkOsResult;
+    /// # use workos::organizations::*;
+    /// use workos::{ApiKey, Metadata, WorkOs, WorkOsError};
+    ///
+    /// # async fn run() -> WorkOsResult<(), ()> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    /// let organizations = workos.organizations();
+    ///
+    /// let report = organizations
+    ///     .for_each_organization(
+    ///         &Default::default(),
+    ///         |organization| {
+    ///             let organizations = &organizations;
+    ///             async move {
+    ///                 organizations
+    ///                     .update_organization(&UpdateOrganizationParams {
+    ///                         organization_id: &organization.id,
+    ///                         name: None,
+    ///                         domain_data: None,
+    ///                         stripe_customer_id: None,
+    ///                         external_id: None,
+    ///                         metadata: Some(Metadata(HashMap::from([(
+    ///                             "region".to_string(),
+    ///                             "eu".to_string(),
+    ///                         )]))),
+    ///                     })
+    ///                     .await?;
+    ///
+    ///                 Ok::<(), WorkOsError<UpdateOrganizationError>>(())
+    ///             }
+    ///         },
+    ///         WorkOsError::retry_after,
+    ///     )
+    ///     .await?;
+    ///
+    /// for failure in report.failed {
+    ///     eprintln!("{}: {}", failure.organization_id, failure.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn for_each_organization<H, F, E, R>(
+        &self,
+        params: &ForEachOrganizationParams<'_>,
+        handler: H,
+        retry_after: R,
+    ) -> WorkOsResult<ForEachOrganizationReport<E>, ()>
+    where
+        H: Fn(Organization) -> F + Send + Sync,
+        F: Future<Output = Result<(), E>> + Send,
+        E: Send,
+        R: Fn(&E) -> Option<Duration> + Send + Sync;
+}
+
+#[async_trait]
+impl ForEachOrganization for Organizations<'_> {
+    async fn for_each_organization<H, F, E, R>(
+        &self,
+        params: &ForEachOrganizationParams<'_>,
+        handler: H,
+        retry_after: R,
+    ) -> WorkOsResult<ForEachOrganizationReport<E>, ()>
+    where
+        H: Fn(Organization) -> F + Send + Sync,
+        F: Future<Output = Result<(), E>> + Send,
+        E: Send,
+        R: Fn(&E) -> Option<Duration> + Send + Sync,
+    {
+        let report = Mutex::new(ForEachOrganizationReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        });
+
+        let mut after = None;
+        loop {
+            let list_params = ListOrganizationsParams {
+                pagination: PaginationParams {
+                    after: after.as_deref(),
+                    limit: Some(PAGE_SIZE),
+                    ..Default::default()
+                },
+                domains: params.domains.clone().map(Into::into),
+            };
+
+            let page = retry(
+                params.retry_policy(),
+                || async {
+                    self.workos.throttle().await;
+                    self.list_organizations(&list_params).await
+                },
+                WorkOsError::retry_after,
+            )
+            .await?;
+
+            stream::iter(page.data)
+                .for_each_concurrent(params.max_concurrency.get(), |organization| {
+                    let report = &report;
+                    let handler = &handler;
+                    let retry_after = &retry_after;
+                    async move {
+                        let organization_id = organization.id.clone();
+                        let result = retry(
+                            params.retry_policy(),
+                            || async {
+                                self.workos.throttle().await;
+                                handler(organization.clone()).await
+                            },
+                            retry_after,
+                        )
+                        .await;
+
+                        let mut report = report.lock().unwrap_or_else(|err| err.into_inner());
+                        match result {
+                            Ok(()) => report.succeeded.push(organization_id),
+                            Err(error) => report.failed.push(ForEachOrganizationFailure {
+                                organization_id,
+                                error,
+                            }),
+                        }
+                    }
+                })
+                .await;
+
+            match page.metadata.after {
+                Some(cursor) => after = Some(cursor),
+                None => break,
+            }
+        }
+
+        Ok(report.into_inner().unwrap_or_else(|err| err.into_inner()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Instant;
+
+    use mockito::Matcher;
+    use serde_json::{Value, json};
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn organization(id: &str) -> Value {
+        json!({
+            "id": id,
+            "object": "organization",
+            "name": "Foo Corp",
+            "allow_profiles_outside_organization": false,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "domains": []
+        })
+    }
+
+    #[tokio::test]
+    async fn it_runs_the_handler_for_every_organization() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded("limit".to_string(), "100".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [organization("org_1"), organization("org_2")],
+                    "list_metadata": {
+                        "before": null,
+                        "after": "org_2"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let rate_limited = server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded(
+                "after".to_string(),
+                "org_2".to_string(),
+            ))
+            .with_status(429)
+            .with_header("Retry-After", "1")
+            .expect(1)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded(
+                "after".to_string(),
+                "org_2".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [organization("org_3")],
+                    "list_metadata": {
+                        "before": "org_3",
+                        "after": null
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let attempts = AtomicUsize::new(0);
+        let started_at = Instant::now();
+
+        let report = workos
+            .organizations()
+            .for_each_organization(
+                &ForEachOrganizationParams {
+                    retry_delay: Duration::from_millis(1),
+                    ..Default::default()
+                },
+                |organization| {
+                    let attempts = &attempts;
+                    async move {
+                        if organization.id == OrganizationId::from("org_1")
+                            && attempts.fetch_add(1, Ordering::Relaxed) == 0
+                        {
+                            Err("busy")
+                        } else if organization.id == OrganizationId::from("org_2") {
+                            Err("failed")
+                        } else {
+                            Ok(())
+                        }
+                    }
+                },
+                |error: &&str| (*error == "busy").then_some(Duration::ZERO),
+            )
+            .await
+            .unwrap();
+
+        rate_limited.assert_async().await;
+
+        assert!(started_at.elapsed() >= Duration::from_secs(1));
+        assert_eq!(attempts.load(Ordering::Relaxed), 2);
+
+        let mut succeeded = report.succeeded.clone();
+        succeeded.sort();
+
+        assert_eq!(
+            succeeded,
+            vec![OrganizationId::from("org_1"), OrganizationId::from("org_3")]
+        );
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(
+            report.failed[0].organization_id,
+            OrganizationId::from("org_2")
+        );
+        assert_eq!(report.failed[0].error, "failed");
+    }
+}
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::organizations::{Organization, OrganizationId, Organizations};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetOrganization`].
 #[derive(Debug, Error)]
@@ -55,17 +55,7 @@ impl GetOrganization for Organizations<'_> {
             .base_url()
             .join(&format!("/organizations/{id}"))?;
 
-        let organization = self
-            .workos
-            .client()
-            .get(url)
-            .bearer_auth(self.workos.key())
-            .send()
-            .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Organization>()
-            .await?;
+        let organization = self.workos.get_json(url).await?;
 
         Ok(organization)
     }
@@ -17,6 +17,25 @@ pub struct RoleId(String);
 #[from(forward)]
 pub struct RoleSlug(String);
 
+impl AsRef<str> for RoleSlug {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The slug of a permission.
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct PermissionSlug(String);
+
+impl AsRef<str> for PermissionSlug {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 /// The slug of a [`Role`].
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RoleSlugObject {
@@ -49,7 +68,7 @@ pub struct Role {
     pub slug: RoleSlug,
 
     /// A list of permission slugs assigned to the role.
-    pub permissions: Vec<String>,
+    pub permissions: Vec<PermissionSlug>,
 
     /// The type of role.
     pub r#type: RoleType,
@@ -63,10 +82,10 @@ pub struct Role {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RoleEvent {
     /// A unique key to reference the role.
-    pub slug: String,
+    pub slug: RoleSlug,
 
     /// A list of permission slugs assigned to the role.
-    pub permissions: Vec<String>,
+    pub permissions: Vec<PermissionSlug>,
 
     /// The timestamps for the role.
     #[serde(flatten)]
@@ -171,7 +171,7 @@ mod test {
                   "profile": {
                     "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
                     "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
-                    "connection_type": "okta",
+                    "connection_type": "OktaSAML",
                     "email": "todd@foo-corp.com",
                     "first_name": "Todd",
                     "idp_id": "00u1a0ufowBJlzPlk357",
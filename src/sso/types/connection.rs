@@ -1,8 +1,9 @@
+use chrono::TimeDelta;
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
 use crate::organizations::OrganizationId;
-use crate::sso::ConnectionType;
+use crate::sso::{ConnectionType, SamlCertificate};
 use crate::{KnownOrUnknown, Timestamps};
 
 /// The ID of a [`Connection`].
@@ -42,11 +43,25 @@ pub struct Connection {
     /// The state of the connection.
     pub state: KnownOrUnknown<ConnectionState, String>,
 
+    /// The X.509 certificates of a SAML connection.
+    pub saml_certificates: Option<Vec<SamlCertificate>>,
+
     /// The timestamps for the connection.
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
 
+impl Connection {
+    /// The SAML certificates that expire within the given duration from now, or have already expired.
+    pub fn expiring_within(&self, duration: TimeDelta) -> Vec<&SamlCertificate> {
+        self.saml_certificates
+            .iter()
+            .flatten()
+            .filter(|certificate| certificate.expires_within(duration))
+            .collect()
+    }
+}
+
 /// [WorkOS Docs: Connection events](https://workos.com/docs/events/connection)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConnectionEvent {
@@ -61,8 +76,10 @@ pub struct ConnectionEvent {
 mod test {
     use serde_json::json;
 
+    use chrono::{TimeDelta, Utc};
+
     use crate::organizations::OrganizationId;
-    use crate::sso::ConnectionType;
+    use crate::sso::{ConnectionType, SamlCertificate, SamlCertificateType};
     use crate::{KnownOrUnknown, Timestamp, Timestamps};
 
     use super::{Connection, ConnectionId, ConnectionState};
@@ -92,6 +109,7 @@ mod test {
                 r#type: KnownOrUnknown::Known(ConnectionType::GoogleOauth),
                 name: "Foo Corp".to_string(),
                 state: KnownOrUnknown::Known(ConnectionState::Active),
+                saml_certificates: None,
                 timestamps: Timestamps {
                     created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
                     updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
@@ -100,6 +118,7 @@ mod test {
         )
     }
 
+    #[cfg(not(feature = "strict"))]
     #[test]
     fn it_deserializes_unknown_connection_types() {
         let connection: Connection = serde_json::from_str(
@@ -122,4 +141,73 @@ mod test {
             KnownOrUnknown::Unknown("UnknownType".to_string())
         )
     }
+
+    #[test]
+    fn it_returns_the_saml_certificates_expiring_within_a_duration() {
+        let certificate = |r#type, expires_in: TimeDelta| SamlCertificate {
+            r#type: KnownOrUnknown::Known(r#type),
+            value: None,
+            expiry_date: Some(Timestamp((Utc::now() + expires_in).fixed_offset())),
+        };
+
+        let connection = Connection {
+            id: ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5"),
+            organization_id: None,
+            r#type: KnownOrUnknown::Known(ConnectionType::OktaSaml),
+            name: "Foo Corp".to_string(),
+            state: KnownOrUnknown::Known(ConnectionState::Active),
+            saml_certificates: Some(vec![
+                certificate(SamlCertificateType::ResponseSigning, TimeDelta::days(7)),
+                certificate(SamlCertificateType::RequestSigning, TimeDelta::days(365)),
+                certificate(SamlCertificateType::ResponseEncryption, TimeDelta::days(-1)),
+            ]),
+            timestamps: Timestamps {
+                created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+            },
+        };
+
+        assert_eq!(
+            connection
+                .expiring_within(TimeDelta::days(30))
+                .into_iter()
+                .map(|certificate| certificate.r#type.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                KnownOrUnknown::Known(SamlCertificateType::ResponseSigning),
+                KnownOrUnknown::Known(SamlCertificateType::ResponseEncryption)
+            ]
+        );
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn it_deserializes_saml_certificates_of_unknown_types() {
+        let connection: Connection = serde_json::from_str(
+            &json!({
+              "object": "connection",
+              "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+              "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+              "connection_type": "OktaSAML",
+              "name": "Foo Corp",
+              "state": "active",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "saml_certificates": [
+                {
+                  "certificate_type": "MetadataSigning",
+                  "value": null,
+                  "expiry_date": "2031-01-15T12:00:00.000Z"
+                }
+              ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            connection.saml_certificates.unwrap()[0].r#type,
+            KnownOrUnknown::Unknown("MetadataSigning".to_string())
+        )
+    }
 }
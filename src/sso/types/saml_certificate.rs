@@ -1,8 +1,11 @@
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
+use x509_cert::Certificate;
+use x509_cert::der::DecodePem;
 
-use crate::Timestamp;
+use crate::{KnownOrUnknown, Timestamp};
 
-/// The state of an [`Invitation`].
+/// The type of a SAML certificate.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SamlCertificateType {
     /// The certificate type is request signing.
@@ -15,6 +18,64 @@ pub enum SamlCertificateType {
     ResponseSigning,
 }
 
+/// An X.509 certificate of a SAML [`Connection`](crate::sso::Connection).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SamlCertificate {
+    /// The type of the certificate.
+    #[serde(rename = "certificate_type")]
+    pub r#type: KnownOrUnknown<SamlCertificateType, String>,
+
+    /// The PEM encoded certificate.
+    pub value: Option<String>,
+
+    /// The expiry date of the certificate, as returned by the WorkOS API.
+    pub expiry_date: Option<Timestamp>,
+}
+
+impl SamlCertificate {
+    /// The end of the validity period of the certificate (its `notAfter` date).
+    ///
+    /// This is read from the PEM encoded [`value`](SamlCertificate::value) when it can be parsed,
+    /// and falls back to the [`expiry_date`](SamlCertificate::expiry_date) returned by the API.
+    /// Returns `None` if neither is available.
+    pub fn not_after(&self) -> Option<Timestamp> {
+        self.value
+            .as_deref()
+            .and_then(parse_not_after)
+            .or_else(|| self.expiry_date.clone())
+    }
+
+    /// Whether the certificate has expired.
+    ///
+    /// Returns `false` if the expiry of the certificate is unknown.
+    pub fn is_expired(&self) -> bool {
+        self.not_after()
+            .is_some_and(|not_after| not_after.0 <= Utc::now().fixed_offset())
+    }
+
+    /// Whether the certificate expires within the given duration from now, or has already expired.
+    ///
+    /// Returns `false` if the expiry of the certificate is unknown.
+    pub fn expires_within(&self, duration: TimeDelta) -> bool {
+        self.not_after()
+            .is_some_and(|not_after| not_after.0 <= Utc::now().fixed_offset() + duration)
+    }
+}
+
+/// Reads the `notAfter` date of a PEM encoded X.509 certificate.
+fn parse_not_after(pem: &str) -> Option<Timestamp> {
+    let certificate = Certificate::from_pem(pem.trim()).ok()?;
+    let since_epoch = certificate
+        .tbs_certificate
+        .validity
+        .not_after
+        .to_unix_duration();
+
+    let not_after = DateTime::from_timestamp(since_epoch.as_secs().try_into().ok()?, 0)?;
+
+    Some(Timestamp(not_after.fixed_offset()))
+}
+
 /// [WorkOS Docs: Connection events](https://workos.com/docs/events/connection)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SamlCertificateEvent {
@@ -28,3 +89,57 @@ pub struct SamlCertificateEvent {
     /// Whether the certificated is expired.
     pub is_expired: Option<bool>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIBhDCCASmgAwIBAgIUTchRtlYS26XLp9Ckvvkx3qfe0WEwCgYIKoZIzj0EAwIw
+FzEVMBMGA1UEAwwMRm9vIENvcnAgSWRQMB4XDTI1MDEwMTAwMDAwMFoXDTMxMDEx
+NTEyMDAwMFowFzEVMBMGA1UEAwwMRm9vIENvcnAgSWRQMFkwEwYHKoZIzj0CAQYI
+KoZIzj0DAQcDQgAEWnwEBJv3jLySnz10+qVarUt0Yfa1C0v0jWrop4PXTUFii4/E
+2Uk9Qv3Bvqyg5uvWAFVHzW4kvydwnMkFX1i8qKNTMFEwHQYDVR0OBBYEFF5/N/MV
+2/w3sJQibfVOGFE3Ok7fMB8GA1UdIwQYMBaAFF5/N/MV2/w3sJQibfVOGFE3Ok7f
+MA8GA1UdEwEB/wQFMAMBAf8wCgYIKoZIzj0EAwIDSQAwRgIhAJyxve82EeLVUWof
+YyOJwXc6VDl62VHXLtX1/NEf4LU1AiEA8B+NgtOP656VZaGxsPOC09y1BBypiBEj
+2TWssXqzRQ0=
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn it_reads_the_not_after_date_from_the_certificate() {
+        let certificate = SamlCertificate {
+            r#type: KnownOrUnknown::Known(SamlCertificateType::ResponseSigning),
+            value: Some(CERTIFICATE.to_string()),
+            expiry_date: Some(Timestamp::try_from("2030-01-01T00:00:00.000Z").unwrap()),
+        };
+
+        assert_eq!(
+            certificate.not_after(),
+            Some(Timestamp::try_from("2031-01-15T12:00:00.000Z").unwrap())
+        );
+    }
+
+    #[test]
+    fn it_falls_back_to_the_expiry_date() {
+        let certificate = SamlCertificate {
+            r#type: KnownOrUnknown::Known(SamlCertificateType::ResponseSigning),
+            value: Some("not a certificate".to_string()),
+            expiry_date: Some(Timestamp::try_from("2030-01-01T00:00:00.000Z").unwrap()),
+        };
+
+        assert_eq!(
+            certificate.not_after(),
+            Some(Timestamp::try_from("2030-01-01T00:00:00.000Z").unwrap())
+        );
+
+        let certificate = SamlCertificate {
+            expiry_date: None,
+            ..certificate
+        };
+
+        assert_eq!(certificate.not_after(), None);
+        assert!(!certificate.expires_within(TimeDelta::days(30)));
+    }
+}
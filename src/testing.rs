@@ -0,0 +1,289 @@
+//! Utilities for testing applications that use WorkOS, without contacting the WorkOS API.
+//!
+//! Requires the `testing` feature.
+
+use aead::OsRng;
+use chrono::{DateTime, Utc};
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use rsa::RsaPrivateKey;
+use rsa::pkcs1::EncodeRsaPrivateKey;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::RemoteJwkSet;
+use crate::organizations::OrganizationId;
+use crate::roles::{PermissionSlug, RoleSlug};
+use crate::sso::AccessToken;
+use crate::user_management::{
+    CookieSession, Impersonator, RefreshToken, SealDataError, SessionCookieData, SessionId, User,
+};
+
+/// The ID of the key in a [`TestJwks`].
+const KEY_ID: &str = "test_key";
+
+/// An error returned from [`TestJwks::generate`].
+#[derive(Debug, Error)]
+pub enum GenerateTestJwksError {
+    /// RSA error.
+    #[error(transparent)]
+    Rsa(#[from] rsa::Error),
+
+    /// PKCS#1 error.
+    #[error(transparent)]
+    Pkcs1(#[from] rsa::pkcs1::Error),
+
+    /// JWT error.
+    #[error(transparent)]
+    Jwt(#[from] jsonwebtoken::errors::Error),
+}
+
+/// An error returned from [`TestJwks::seal_session`].
+#[derive(Debug, Error)]
+pub enum SealTestSessionError {
+    /// JWT error.
+    #[error(transparent)]
+    Jwt(#[from] jsonwebtoken::errors::Error),
+
+    /// Seal data error.
+    #[error(transparent)]
+    SealData(#[from] SealDataError),
+}
+
+/// The claims of an access token minted by [`TestJwks`].
+#[derive(Clone, Debug, Serialize)]
+pub struct TestSessionClaims {
+    /// The ID of the session.
+    #[serde(rename = "sid")]
+    pub session_id: SessionId,
+
+    /// The organization the user selected to sign in to.
+    #[serde(rename = "org_id", skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<OrganizationId>,
+
+    /// The role of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<RoleSlug>,
+
+    /// The roles of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<RoleSlug>>,
+
+    /// A list of permissions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<PermissionSlug>>,
+
+    /// A list of entitlements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entitlements: Option<Vec<String>>,
+
+    /// A list of feature flags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature_flags: Option<Vec<String>>,
+
+    /// When the access token expires. Sessions are rejected after this time.
+    #[serde(
+        rename = "exp",
+        with = "chrono::serde::ts_seconds_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Any additional claims.
+    #[serde(flatten)]
+    pub custom: Map<String, Value>,
+}
+
+impl TestSessionClaims {
+    /// Returns new [`TestSessionClaims`] for the given session, without any other claims.
+    pub fn new(session_id: SessionId) -> Self {
+        Self {
+            session_id,
+            organization_id: None,
+            role: None,
+            roles: None,
+            permissions: None,
+            entitlements: None,
+            feature_flags: None,
+            expires_at: None,
+            custom: Map::new(),
+        }
+    }
+}
+
+/// A locally generated JSON Web Key Set (JWKS) that mints access tokens and sealed sessions.
+///
+/// Point the client at the key set using [`WorkOsBuilder::jwks`](crate::WorkOsBuilder::jwks)
+/// and [`TestJwks::remote_jwk_set`], so the sessions it mints are accepted by
+/// [`CookieSession::authenticate`].
+///
+/// # Examples
+///
+/// ```
+/// use workos::testing::{TestJwks, TestSessionClaims};
+/// use workos::user_management::SessionId;
+/// use workos::{ApiKey, WorkOs};
+///
+/// let jwks = TestJwks::generate().unwrap();
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .jwks(jwks.remote_jwk_set())
+///     .build();
+///
+/// let access_token = jwks
+///     .sign(&TestSessionClaims::new(SessionId::from("session_123")))
+///     .unwrap();
+/// ```
+pub struct TestJwks {
+    encoding_key: EncodingKey,
+    jwks: JwkSet,
+}
+
+impl TestJwks {
+    /// Generates a new RSA key pair.
+    pub fn generate() -> Result<Self, GenerateTestJwksError> {
+        let private_key = RsaPrivateKey::new(&mut OsRng, 2048)?;
+        let encoding_key = EncodingKey::from_rsa_der(private_key.to_pkcs1_der()?.as_bytes());
+
+        let mut jwk = Jwk::from_encoding_key(&encoding_key, Algorithm::RS256)?;
+        jwk.common.key_id = Some(KEY_ID.to_string());
+
+        Ok(Self {
+            encoding_key,
+            jwks: JwkSet { keys: vec![jwk] },
+        })
+    }
+
+    /// The public keys of the key set.
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
+    }
+
+    /// Returns a [`RemoteJwkSet`] that serves the public keys of the key set.
+    pub fn remote_jwk_set(&self) -> RemoteJwkSet {
+        RemoteJwkSet::from_jwks(self.jwks.clone())
+    }
+
+    /// Mints an access token with the given claims.
+    pub fn sign(
+        &self,
+        claims: &TestSessionClaims,
+    ) -> Result<AccessToken, jsonwebtoken::errors::Error> {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(KEY_ID.to_string());
+
+        encode(&header, claims, &self.encoding_key).map(AccessToken::from)
+    }
+
+    /// Mints a sealed session cookie for the user with the given claims, encrypted with the cookie password.
+    pub fn seal_session(
+        &self,
+        user: User,
+        claims: &TestSessionClaims,
+        impersonator: Option<Impersonator>,
+        cookie_password: &str,
+    ) -> Result<String, SealTestSessionError> {
+        let access_token = self.sign(claims)?;
+
+        Ok(CookieSession::seal_data(
+            SessionCookieData {
+                user,
+                organization_id: claims.organization_id.clone(),
+                access_token,
+                refresh_token: RefreshToken::from("test_refresh_token"),
+                impersonator,
+            },
+            cookie_password,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeDelta;
+
+    use crate::user_management::{AuthenticateWithSessionCookieError, UserId};
+    use crate::{ApiKey, Timestamps, WorkOs};
+
+    use super::*;
+
+    fn user() -> User {
+        User {
+            id: UserId::from("user_01H5JQDV7R7ATEYZDEG0W5PRYS"),
+            email: "test@example.com".to_string(),
+            email_verified: true,
+            first_name: None,
+            last_name: None,
+            profile_picture_url: None,
+            last_sign_in_at: None,
+            external_id: None,
+            metadata: None,
+            timestamps: Timestamps {
+                created_at: "2021-06-25T19:07:33.155Z".try_into().unwrap(),
+                updated_at: "2021-06-25T19:07:33.155Z".try_into().unwrap(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn it_mints_sessions_accepted_by_the_verifier() {
+        let jwks = TestJwks::generate().unwrap();
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .jwks(jwks.remote_jwk_set())
+            .build();
+
+        let cookie_password = "alongcookiesecretmadefortestingsessions";
+        let valid = jwks
+            .seal_session(
+                user(),
+                &TestSessionClaims {
+                    organization_id: Some(OrganizationId::from("org_123")),
+                    role: Some(RoleSlug::from("admin")),
+                    permissions: Some(vec![PermissionSlug::from("posts:create")]),
+                    expires_at: Some(Utc::now() + TimeDelta::minutes(5)),
+                    ..TestSessionClaims::new(SessionId::from("session_123"))
+                },
+                None,
+                cookie_password,
+            )
+            .unwrap();
+        let expired = jwks
+            .seal_session(
+                user(),
+                &TestSessionClaims {
+                    expires_at: Some(Utc::now() - TimeDelta::minutes(5)),
+                    ..TestSessionClaims::new(SessionId::from("session_456"))
+                },
+                None,
+                cookie_password,
+            )
+            .unwrap();
+
+        let user_management = workos.user_management();
+
+        let response = user_management
+            .load_sealed_session(&valid, cookie_password)
+            .authenticate()
+            .await
+            .unwrap();
+
+        assert_eq!(response.session_id, SessionId::from("session_123"));
+        assert_eq!(
+            response.organization_id,
+            Some(OrganizationId::from("org_123"))
+        );
+        assert!(response.has_role("admin"));
+        assert!(response.has_permission("posts:create"));
+
+        let response = user_management
+            .load_sealed_session(&expired, cookie_password)
+            .authenticate()
+            .await;
+
+        assert!(matches!(
+            response,
+            Err(AuthenticateWithSessionCookieError::InvalidJwt(_))
+        ));
+    }
+}
@@ -132,7 +132,8 @@ impl<'a> CookieSession<'a> {
         Ok(AuthenticateWithSessionCookieResponse {
             session_id: decoded.claims.sid.into(),
             organization_id: decoded.claims.org_id.map(Into::into),
-            role: decoded.claims.role.map(Into::into),
+            role: decoded.claims.role,
+            roles: decoded.claims.roles,
             permissions: decoded.claims.permissions,
             entitlements: decoded.claims.entitlements,
             feature_flags: decoded.claims.feature_flags,
@@ -245,7 +246,7 @@ mod tests {
     use crate::{
         ApiKey, Timestamps, WorkOs,
         organizations::OrganizationId,
-        roles::RoleSlug,
+        roles::{PermissionSlug, RoleSlug},
         sso::{AccessToken, ClientId},
         user_management::{
             AuthenticateWithSessionCookieError, AuthenticateWithSessionCookieResponse,
@@ -384,8 +385,11 @@ mod tests {
                 session_id: SessionId::from("session_123"),
                 organization_id: Some(OrganizationId::from("org_123")),
                 role: Some(RoleSlug::from("member")),
-                // roles: ["member", "admin"],
-                permissions: Some(vec!["posts:create".to_string(), "posts:delete".to_string()]),
+                roles: Some(vec![RoleSlug::from("member"), RoleSlug::from("admin")]),
+                permissions: Some(vec![
+                    PermissionSlug::from("posts:create"),
+                    PermissionSlug::from("posts:delete")
+                ]),
                 entitlements: Some(vec!["audit-logs".to_string()]),
                 feature_flags: Some(vec!["dark-mode".to_string(), "beta-features".to_string()]),
                 user: User {
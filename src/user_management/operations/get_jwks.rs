@@ -132,7 +132,8 @@ mod test {
             result,
             Err(WorkOsError::Unknown {
                 status: StatusCode::NOT_FOUND,
-                body: JsonOrText::Json(json)
+                body: JsonOrText::Json(json),
+                ..
             }) if json == json!({
                 "message": "Not Found"
             })
@@ -3,7 +3,7 @@ use thiserror::Error;
 use crate::{
     FindJwkError, WorkOsError,
     organizations::OrganizationId,
-    roles::RoleSlug,
+    roles::{PermissionSlug, RoleSlug},
     sso::AccessToken,
     user_management::{Impersonator, JwksError, SessionId, UnsealDataError, User},
 };
@@ -48,8 +48,11 @@ pub struct AuthenticateWithSessionCookieResponse {
     /// The role of the user.
     pub role: Option<RoleSlug>,
 
+    /// The roles of the user.
+    pub roles: Option<Vec<RoleSlug>>,
+
     /// A list of permission slugs.
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<PermissionSlug>>,
 
     /// A list of entitlements.
     pub entitlements: Option<Vec<String>>,
@@ -66,3 +69,109 @@ pub struct AuthenticateWithSessionCookieResponse {
     /// A JWT containing information about the session.
     pub access_token: AccessToken,
 }
+
+impl AuthenticateWithSessionCookieResponse {
+    /// Whether the user has the given role, either as their role or as one of their roles.
+    ///
+    /// Accepts a [`RoleSlug`], a string or any [`KnownOrUnknown`](crate::KnownOrUnknown) of those.
+    pub fn has_role(&self, role: impl AsRef<str>) -> bool {
+        let role = role.as_ref();
+
+        self.role.iter().any(|slug| slug.as_ref() == role)
+            || self
+                .roles
+                .iter()
+                .flatten()
+                .any(|slug| slug.as_ref() == role)
+    }
+
+    /// Whether the user has the given permission.
+    ///
+    /// Accepts a [`PermissionSlug`], a string or any [`KnownOrUnknown`](crate::KnownOrUnknown) of those.
+    pub fn has_permission(&self, permission: impl AsRef<str>) -> bool {
+        let permission = permission.as_ref();
+
+        self.permissions
+            .iter()
+            .flatten()
+            .any(|slug| slug.as_ref() == permission)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        KnownOrUnknown, Timestamps,
+        user_management::{SessionId, UserId},
+    };
+
+    use super::*;
+
+    #[derive(Debug)]
+    enum Permission {
+        PostsCreate,
+    }
+
+    impl AsRef<str> for Permission {
+        fn as_ref(&self) -> &str {
+            match self {
+                Permission::PostsCreate => "posts:create",
+            }
+        }
+    }
+
+    fn response() -> AuthenticateWithSessionCookieResponse {
+        AuthenticateWithSessionCookieResponse {
+            session_id: SessionId::from("session_123"),
+            organization_id: Some(OrganizationId::from("org_123")),
+            role: Some(RoleSlug::from("member")),
+            roles: Some(vec![RoleSlug::from("member"), RoleSlug::from("billing")]),
+            permissions: Some(vec![PermissionSlug::from("posts:create")]),
+            entitlements: None,
+            feature_flags: None,
+            user: User {
+                id: UserId::from("user_01H5JQDV7R7ATEYZDEG0W5PRYS"),
+                email: "test@example.com".to_string(),
+                email_verified: true,
+                first_name: None,
+                last_name: None,
+                profile_picture_url: None,
+                last_sign_in_at: None,
+                external_id: None,
+                metadata: None,
+                timestamps: Timestamps {
+                    created_at: "2021-06-25T19:07:33.155Z".try_into().unwrap(),
+                    updated_at: "2021-06-25T19:07:33.155Z".try_into().unwrap(),
+                },
+            },
+            impersonator: None,
+            access_token: AccessToken::from("eyJ"),
+        }
+    }
+
+    #[test]
+    fn it_checks_roles() {
+        let response = response();
+
+        assert!(response.has_role(RoleSlug::from("member")));
+        assert!(response.has_role("billing"));
+        assert!(!response.has_role("admin"));
+    }
+
+    #[test]
+    fn it_checks_permissions() {
+        let response = response();
+
+        assert!(response.has_permission(PermissionSlug::from("posts:create")));
+        assert!(
+            response.has_permission(KnownOrUnknown::<Permission, String>::Known(
+                Permission::PostsCreate
+            ))
+        );
+        assert!(
+            !response.has_permission(KnownOrUnknown::<Permission, String>::Unknown(
+                "posts:delete".to_string()
+            ))
+        );
+    }
+}
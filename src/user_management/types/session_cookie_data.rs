@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     organizations::OrganizationId,
+    roles::{PermissionSlug, RoleSlug},
     sso::AccessToken,
     user_management::{Impersonator, RefreshToken, User},
 };
@@ -16,10 +17,13 @@ pub struct AccessTokenClaims {
     pub org_id: Option<String>,
 
     /// The role of the user.
-    pub role: Option<String>,
+    pub role: Option<RoleSlug>,
+
+    /// The roles of the user.
+    pub roles: Option<Vec<RoleSlug>>,
 
     /// A list of permissions.
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<PermissionSlug>>,
 
     /// A list of entitlements.
     pub entitlements: Option<Vec<String>>,
@@ -28,6 +32,34 @@ pub struct AccessTokenClaims {
     pub feature_flags: Option<Vec<String>>,
 }
 
+impl AccessTokenClaims {
+    /// Whether the user has the given role, either as their role or as one of their roles.
+    ///
+    /// Accepts a [`RoleSlug`], a string or any [`KnownOrUnknown`](crate::KnownOrUnknown) of those.
+    pub fn has_role(&self, role: impl AsRef<str>) -> bool {
+        let role = role.as_ref();
+
+        self.role.iter().any(|slug| slug.as_ref() == role)
+            || self
+                .roles
+                .iter()
+                .flatten()
+                .any(|slug| slug.as_ref() == role)
+    }
+
+    /// Whether the user has the given permission.
+    ///
+    /// Accepts a [`PermissionSlug`], a string or any [`KnownOrUnknown`](crate::KnownOrUnknown) of those.
+    pub fn has_permission(&self, permission: impl AsRef<str>) -> bool {
+        let permission = permission.as_ref();
+
+        self.permissions
+            .iter()
+            .flatten()
+            .any(|slug| slug.as_ref() == permission)
+    }
+}
+
 /// The data in a session cookie.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SessionCookieData {
@@ -46,3 +78,30 @@ pub struct SessionCookieData {
     /// The WorkOS Dashboard user who is impersonating the user.
     pub impersonator: Option<Impersonator>,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_checks_roles_and_permissions() {
+        let claims: AccessTokenClaims = serde_json::from_value(json!({
+            "sid": "session_123",
+            "org_id": "org_123",
+            "role": "member",
+            "roles": ["member", "billing"],
+            "permissions": ["posts:create"],
+            "entitlements": null,
+            "feature_flags": null
+        }))
+        .unwrap();
+
+        assert!(claims.has_role(RoleSlug::from("member")));
+        assert!(claims.has_role("billing"));
+        assert!(!claims.has_role("admin"));
+        assert!(claims.has_permission(PermissionSlug::from("posts:create")));
+        assert!(!claims.has_permission("posts:delete"));
+    }
+}
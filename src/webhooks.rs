@@ -0,0 +1,7 @@
+//! A module for working with WorkOS webhooks.
+//!
+//! [WorkOS Docs: Webhooks](https://workos.com/docs/events/data-syncing/webhooks)
+
+mod types;
+
+pub use types::*;
@@ -0,0 +1,3 @@
+mod webhook;
+
+pub use webhook::*;
@@ -0,0 +1,129 @@
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::Timestamp;
+use crate::events::{EventData, WorkOsEvent};
+
+/// The ID of a [`Webhook`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct WebhookId(String);
+
+/// The payload of a webhook request sent by WorkOS.
+///
+/// [WorkOS Docs: Webhooks](https://workos.com/docs/events/data-syncing/webhooks)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+    /// Unique identifier for the webhook.
+    pub id: WebhookId,
+
+    /// Event data.
+    #[serde(flatten)]
+    pub data: EventData,
+
+    /// Timestamp of when the event occurred.
+    pub created_at: Timestamp,
+}
+
+impl WorkOsEvent for Webhook {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn data(&self) -> &EventData {
+        &self.data
+    }
+
+    fn into_data(self) -> EventData {
+        self.data
+    }
+
+    fn created_at(&self) -> &Timestamp {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::events::{Event, EventName};
+    use crate::organizations::OrganizationId;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_a_webhook() {
+        let webhook: Webhook = serde_json::from_str(
+            &json!({
+                "id": "wh_01G69A99TDXHK89EDVGDRC6K3S",
+                "event": "organization.updated",
+                "data": {
+                    "object": "organization",
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "name": "Foo Corp",
+                    "allow_profiles_outside_organization": false,
+                    "domains": [],
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                },
+                "created_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(webhook.id, WebhookId::from("wh_01G69A99TDXHK89EDVGDRC6K3S"));
+        assert_eq!(webhook.name(), EventName::OrganizationUpdated);
+        assert_eq!(
+            webhook.organization_id(),
+            Some(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+        );
+    }
+
+    #[test]
+    fn it_handles_webhooks_and_events_with_the_same_handler() {
+        fn handle(event: impl WorkOsEvent) -> (String, EventName) {
+            (event.id().to_string(), event.name())
+        }
+
+        let data = json!({
+            "event": "organization.deleted",
+            "data": {
+                "object": "organization",
+                "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                "name": "Foo Corp",
+                "allow_profiles_outside_organization": false,
+                "domains": [],
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z"
+            },
+            "created_at": "2021-06-25T19:07:33.155Z"
+        });
+
+        let mut webhook = data.clone();
+        webhook["id"] = json!("wh_01G69A99TDXHK89EDVGDRC6K3S");
+        let webhook: Webhook = serde_json::from_value(webhook).unwrap();
+
+        let mut event = data;
+        event["id"] = json!("event_01H2GNQD5D7ZE06FDDS75NFPHY");
+        let event: Event = serde_json::from_value(event).unwrap();
+
+        assert_eq!(
+            handle(webhook),
+            (
+                "wh_01G69A99TDXHK89EDVGDRC6K3S".to_string(),
+                EventName::OrganizationDeleted
+            )
+        );
+        assert_eq!(
+            handle(event),
+            (
+                "event_01H2GNQD5D7ZE06FDDS75NFPHY".to_string(),
+                EventName::OrganizationDeleted
+            )
+        );
+    }
+}
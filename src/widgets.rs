@@ -3,8 +3,10 @@
 //! [WorkOS Docs: Widgets Guide](https://workos.com/docs/authkit/widgets)
 
 mod operations;
+mod types;
 
 pub use operations::*;
+pub use types::*;
 
 use crate::WorkOs;
 
@@ -20,4 +22,20 @@ impl<'a> Widgets<'a> {
     pub fn new(workos: &'a WorkOs) -> Self {
         Self { workos }
     }
+
+    /// Returns the configuration a frontend needs to embed a widget of the given kind.
+    ///
+    /// The token should be generated with [`GenerateToken`] using the [`WidgetKind::scope`] of the widget.
+    pub fn embed_config(&self, kind: WidgetKind, token: &str) -> WidgetEmbedConfig {
+        let base_url = self.workos.base_url();
+
+        WidgetEmbedConfig {
+            widget: kind,
+            component: kind.component(),
+            auth_token: token.to_string(),
+            api_hostname: base_url.host_str().unwrap_or_default().to_string(),
+            https: base_url.scheme() == "https",
+            port: base_url.port(),
+        }
+    }
 }
@@ -0,0 +1,3 @@
+mod widget_embed_config;
+
+pub use widget_embed_config::*;
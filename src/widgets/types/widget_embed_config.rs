@@ -0,0 +1,121 @@
+use derive_more::Display;
+use serde::Serialize;
+
+use crate::widgets::WidgetTokenScope;
+
+/// A kind of WorkOS widget.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WidgetKind {
+    /// [WorkOS Docs: Users Management widget](https://workos.com/docs/widgets/users-management)
+    #[display("users-management")]
+    UsersManagement,
+
+    /// [WorkOS Docs: Admin Portal SSO Connection widget](https://workos.com/docs/widgets/admin-portal-sso-connection)
+    #[display("admin-portal-sso-connection")]
+    AdminPortalSsoConnection,
+
+    /// [WorkOS Docs: Admin Portal Domain Verification widget](https://workos.com/docs/widgets/admin-portal-domain-verification)
+    #[display("admin-portal-domain-verification")]
+    AdminPortalDomainVerification,
+}
+
+impl WidgetKind {
+    /// The scope the widget token must include to render this widget.
+    pub fn scope(&self) -> WidgetTokenScope {
+        match self {
+            WidgetKind::UsersManagement => WidgetTokenScope::ManageUsers,
+            WidgetKind::AdminPortalSsoConnection => WidgetTokenScope::ManageSso,
+            WidgetKind::AdminPortalDomainVerification => WidgetTokenScope::ManageDomainVerification,
+        }
+    }
+
+    /// The name of the component in the `@workos-inc/widgets` package.
+    pub fn component(&self) -> &'static str {
+        match self {
+            WidgetKind::UsersManagement => "UsersManagement",
+            WidgetKind::AdminPortalSsoConnection => "AdminPortalSsoConnection",
+            WidgetKind::AdminPortalDomainVerification => "AdminPortalDomainVerification",
+        }
+    }
+}
+
+/// The configuration a frontend needs to embed a widget.
+///
+/// Serializes to a JSON object whose keys match the props of the `@workos-inc/widgets` components.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetEmbedConfig {
+    /// The kind of widget.
+    pub widget: WidgetKind,
+
+    /// The name of the component to render.
+    pub component: &'static str,
+
+    /// The widget token used to authorize the widget.
+    pub auth_token: String,
+
+    /// The hostname of the WorkOS API.
+    pub api_hostname: String,
+
+    /// Whether the WorkOS API is reached over HTTPS.
+    pub https: bool,
+
+    /// The port of the WorkOS API, if not the default for the scheme.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    #[test]
+    fn it_builds_an_embed_config_for_the_default_api() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let config = workos
+            .widgets()
+            .embed_config(WidgetKind::UsersManagement, "widget_token");
+
+        assert_eq!(
+            serde_json::to_value(config).unwrap(),
+            json!({
+                "widget": "users-management",
+                "component": "UsersManagement",
+                "authToken": "widget_token",
+                "apiHostname": "api.workos.com",
+                "https": true
+            })
+        )
+    }
+
+    #[test]
+    fn it_builds_an_embed_config_for_a_custom_api() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url("http://localhost:8080")
+            .unwrap()
+            .build();
+
+        let config = workos
+            .widgets()
+            .embed_config(WidgetKind::AdminPortalSsoConnection, "widget_token");
+
+        assert_eq!(
+            config,
+            WidgetEmbedConfig {
+                widget: WidgetKind::AdminPortalSsoConnection,
+                component: "AdminPortalSsoConnection",
+                auth_token: "widget_token".to_string(),
+                api_hostname: "localhost".to_string(),
+                https: false,
+                port: Some(8080),
+            }
+        );
+        assert_eq!(config.widget.scope(), WidgetTokenScope::ManageSso);
+    }
+}